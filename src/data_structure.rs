@@ -1,3 +1,10 @@
 pub mod character_set;
+pub mod flags;
+pub mod hall_of_fame;
+pub mod item;
+pub mod layout;
+pub mod pokedex;
 pub mod pokemon;
 pub mod save_data;
+pub mod trainer;
+pub mod vars;
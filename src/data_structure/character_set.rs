@@ -379,3 +379,14 @@ pub fn get_char(index: usize) -> &'static str {
 pub fn get_code(s: &str) -> u8 {
     *get_byte_set().get(s).unwrap()
 }
+
+/// Retrieves the byte value corresponding to a given character string.
+///
+/// # Errors
+/// Returns `CharacterSetError::CharacterNotFound` if the character is not in the character set.
+pub fn try_get_code(s: &str) -> Result<u8, CharacterSetError> {
+    get_byte_set()
+        .get(s)
+        .copied()
+        .ok_or_else(|| CharacterSetError::CharacterNotFound(s.to_string()))
+}
@@ -0,0 +1,200 @@
+//! Event flags, the bit array of the Game State section recording story progress, badges,
+//! items picked up, trainers defeated, etc.
+//!
+//! Flag `n` is bit `n % 8` of byte `n / 8` of the array. The location and size of the array
+//! depend on the game version, see the `layout` module. The array of FireRed/LeafGreen starts
+//! at the end of Team / items and continues into Game State.
+//!
+//! The flag numbers differ between games, the modules below name the well-known ones.
+//!
+//! # Example
+//!
+//! ```rust no_run
+//! use pk_edit::SaveFile;
+//! use pk_edit::data_structure::flags::emerald;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let buffer: Vec<u8> = vec![];
+//! let mut save_file = SaveFile::parse(&buffer)?;
+//!
+//! for badge in emerald::BADGES {
+//!     save_file.set_flag(badge, true)?;
+//! }
+//! save_file.set_flag(emerald::ENABLE_SHIP_SOUTHERN_ISLAND, true)?;
+//! # Ok(())
+//! # }
+//! ```
+use crate::data_structure::save_data::{GameVersion, SaveDataError};
+
+/// Copy of the event flag array of a save file, see `SaveFile::flags`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Flags {
+    data: Vec<u8>,
+}
+
+impl Flags {
+    pub(crate) fn new(data: Vec<u8>) -> Self {
+        Flags { data }
+    }
+
+    pub(crate) fn raw_data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Number of flags in the array.
+    pub fn len(&self) -> usize {
+        self.data.len() * 8
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn get(&self, flag: u16) -> Result<bool, SaveDataError> {
+        let (byte, bit) = self.position(flag)?;
+        Ok(self.data[byte] & bit != 0)
+    }
+
+    pub fn set(&mut self, flag: u16, value: bool) -> Result<(), SaveDataError> {
+        let (byte, bit) = self.position(flag)?;
+
+        if value {
+            self.data[byte] |= bit;
+        } else {
+            self.data[byte] &= !bit;
+        }
+
+        Ok(())
+    }
+
+    fn position(&self, flag: u16) -> Result<(usize, u8), SaveDataError> {
+        if flag as usize >= self.len() {
+            return Err(SaveDataError::ValueOutOfRange {
+                value: flag as u32,
+                max: self.len().saturating_sub(1) as u32,
+            });
+        }
+
+        Ok((flag as usize / 8, 1 << (flag % 8)))
+    }
+}
+
+/// Flags of the gym badges, in the order they are usually earned.
+pub fn badge_flags(version: GameVersion) -> [u16; 8] {
+    match version {
+        GameVersion::RubySapphire => ruby_sapphire::BADGES,
+        GameVersion::Emerald => emerald::BADGES,
+        GameVersion::FireRedLeafGreen => fire_red_leaf_green::BADGES,
+    }
+}
+
+/// Flag set once the National Pokédex is unlocked.
+pub fn national_dex_flag(version: GameVersion) -> u16 {
+    match version {
+        GameVersion::RubySapphire => ruby_sapphire::SYS_NATIONAL_DEX,
+        GameVersion::Emerald => emerald::SYS_NATIONAL_DEX,
+        GameVersion::FireRedLeafGreen => fire_red_leaf_green::SYS_NATIONAL_DEX,
+    }
+}
+
+pub mod ruby_sapphire {
+    pub const SYS_POKEMON_GET: u16 = 0x800;
+    pub const SYS_POKEDEX_GET: u16 = 0x801;
+    pub const SYS_POKENAV_GET: u16 = 0x802;
+    pub const SYS_GAME_CLEAR: u16 = 0x804;
+    pub const BADGES: [u16; 8] = [0x807, 0x808, 0x809, 0x80A, 0x80B, 0x80C, 0x80D, 0x80E];
+    pub const SYS_NATIONAL_DEX: u16 = 0x836;
+    /// Eon Ticket event, the S.S. Tidal sails to Southern Island.
+    pub const SYS_HAS_EON_TICKET: u16 = 0x853;
+    /// S.S. Ticket given by Norman after entering the Hall of Fame.
+    pub const RECEIVED_SS_TICKET: u16 = 0x123;
+
+    // Legendaries, `CAUGHT_*` records the capture and `HIDE_*` removes the Pokémon from its map.
+    pub const CAUGHT_KYOGRE: u16 = 0x1DB;
+    pub const CAUGHT_GROUDON: u16 = 0x1DA;
+    pub const CAUGHT_RAYQUAZA: u16 = 0x1D9;
+    pub const CAUGHT_REGIROCK: u16 = 0x1E4;
+    pub const CAUGHT_REGICE: u16 = 0x1E5;
+    pub const CAUGHT_REGISTEEL: u16 = 0x1E6;
+    pub const CAUGHT_LATIAS_OR_LATIOS: u16 = 0x1C6;
+    pub const HIDE_KYOGRE: u16 = 0x2B9;
+    pub const HIDE_GROUDON: u16 = 0x2BA;
+    pub const HIDE_RAYQUAZA: u16 = 0x2BB;
+    pub const HIDE_REGIROCK: u16 = 0x2BC;
+    pub const HIDE_REGICE: u16 = 0x2BD;
+    pub const HIDE_REGISTEEL: u16 = 0x2BE;
+    pub const HIDE_LATIAS_OR_LATIOS: u16 = 0x2BF;
+}
+
+pub mod emerald {
+    pub const SYS_POKEMON_GET: u16 = 0x860;
+    pub const SYS_POKEDEX_GET: u16 = 0x861;
+    pub const SYS_POKENAV_GET: u16 = 0x862;
+    pub const SYS_GAME_CLEAR: u16 = 0x864;
+    pub const BADGES: [u16; 8] = [0x867, 0x868, 0x869, 0x86A, 0x86B, 0x86C, 0x86D, 0x86E];
+    pub const SYS_NATIONAL_DEX: u16 = 0x896;
+    /// Eon Ticket event, the S.S. Tidal sails to Southern Island.
+    pub const ENABLE_SHIP_SOUTHERN_ISLAND: u16 = 0x8B3;
+    /// Aurora Ticket event, the S.S. Tidal sails to Birth Island.
+    pub const ENABLE_SHIP_BIRTH_ISLAND: u16 = 0x8D5;
+    /// Old Sea Map event, the S.S. Tidal sails to Faraway Island.
+    pub const ENABLE_SHIP_FARAWAY_ISLAND: u16 = 0x8D6;
+    /// Mystic Ticket event, the S.S. Tidal sails to Navel Rock.
+    pub const ENABLE_SHIP_NAVEL_ROCK: u16 = 0x8E0;
+    /// S.S. Ticket given by Norman after entering the Hall of Fame.
+    pub const RECEIVED_SS_TICKET: u16 = 0x123;
+
+    // Legendaries, `CAUGHT_*` records the capture and `HIDE_*` removes the Pokémon from its map.
+    pub const CAUGHT_KYOGRE: u16 = 0x1DB;
+    pub const CAUGHT_GROUDON: u16 = 0x1DA;
+    pub const CAUGHT_RAYQUAZA: u16 = 0x1D9;
+    pub const CAUGHT_REGIROCK: u16 = 0x1D7;
+    pub const CAUGHT_REGICE: u16 = 0x1D8;
+    pub const CAUGHT_REGISTEEL: u16 = 0x1DC;
+    pub const CAUGHT_LATIAS_OR_LATIOS: u16 = 0x1C6;
+    pub const CAUGHT_LUGIA: u16 = 0x1D3;
+    pub const CAUGHT_HO_OH: u16 = 0x1D4;
+    pub const CAUGHT_MEW: u16 = 0x1C8;
+    pub const CAUGHT_DEOXYS: u16 = 0x1DF;
+    pub const HIDE_KYOGRE: u16 = 0x3A6;
+    pub const HIDE_GROUDON: u16 = 0x3A7;
+    pub const HIDE_RAYQUAZA: u16 = 0x3A9;
+    pub const HIDE_REGIROCK: u16 = 0x3AA;
+    pub const HIDE_REGICE: u16 = 0x3AB;
+    pub const HIDE_REGISTEEL: u16 = 0x3AC;
+    pub const HIDE_LATIAS_OR_LATIOS: u16 = 0x3A2;
+    pub const HIDE_LUGIA: u16 = 0x320;
+    pub const HIDE_HO_OH: u16 = 0x321;
+    pub const HIDE_MEW: u16 = 0x32B;
+    pub const HIDE_DEOXYS: u16 = 0x31F;
+}
+
+pub mod fire_red_leaf_green {
+    pub const BADGES: [u16; 8] = [0x820, 0x821, 0x822, 0x823, 0x824, 0x825, 0x826, 0x827];
+    pub const SYS_POKEMON_GET: u16 = 0x828;
+    pub const SYS_POKEDEX_GET: u16 = 0x829;
+    pub const SYS_GAME_CLEAR: u16 = 0x82C;
+    pub const SYS_NATIONAL_DEX: u16 = 0x840;
+    /// Aurora Ticket event, the Seagallop sails to Birth Island.
+    pub const ENABLE_SHIP_BIRTH_ISLAND: u16 = 0x84A;
+    /// Mystic Ticket event, the Seagallop sails to Navel Rock.
+    pub const ENABLE_SHIP_NAVEL_ROCK: u16 = 0x84B;
+    /// S.S. Ticket given by Bill at Sea Cottage.
+    pub const GOT_SS_TICKET: u16 = 0x234;
+
+    // Legendaries, `CAUGHT_*` records the capture and `HIDE_*` removes the Pokémon from its map.
+    pub const CAUGHT_ARTICUNO: u16 = 0x2A0;
+    pub const CAUGHT_ZAPDOS: u16 = 0x2A1;
+    pub const CAUGHT_MOLTRES: u16 = 0x2A2;
+    pub const CAUGHT_MEWTWO: u16 = 0x2A3;
+    pub const CAUGHT_LUGIA: u16 = 0x2A4;
+    pub const CAUGHT_HO_OH: u16 = 0x2A5;
+    pub const CAUGHT_DEOXYS: u16 = 0x2A6;
+    pub const HIDE_ARTICUNO: u16 = 0x03D;
+    pub const HIDE_ZAPDOS: u16 = 0x03E;
+    pub const HIDE_MOLTRES: u16 = 0x03F;
+    pub const HIDE_MEWTWO: u16 = 0x040;
+    pub const HIDE_LUGIA: u16 = 0x041;
+    pub const HIDE_HO_OH: u16 = 0x042;
+    pub const HIDE_DEOXYS: u16 = 0x043;
+}
@@ -0,0 +1,225 @@
+//! Hall of Fame records, stored in the 8 KiB of flash following the game save blocks.
+//!
+//! The records take two sectors at 0x01C000 and 0x01D000, each holding 3968 bytes of data and a
+//! footer. Unlike the game save sections, the checksum of the sector data is stored in place of
+//! the section ID and the save index is left as zero.
+//!
+//!| Offset | Size | Contents    |
+//!|--------|------|-------------|
+//!| 0x0000 | 3968 |  Data       |
+//!| 0x0FF4 |   2  |  Checksum   |
+//!| 0x0FF8 |   4  |  Signature  |
+//!
+//! The data of both sectors put together holds up to 50 teams of 6 Pokémon, oldest team first.
+//! Once all 50 slots are used the oldest team is dropped to make room for the new one.
+//!
+//!| Offset | Size | Contents                                  |
+//!|--------|------|-------------------------------------------|
+//!| 0x0000 |   4  |  OT ID                                    |
+//!| 0x0004 |   4  |  Personality value                        |
+//!| 0x0008 |   2  |  Species index (9 bits) and level (7 bits)|
+//!| 0x000A |  10  |  Nickname                                 |
+use byteorder::{ByteOrder, LittleEndian};
+
+use crate::data_structure::character_set::get_char;
+use crate::data_structure::pokemon::{nat_dex_number, species_index, Pokemon};
+use crate::data_structure::save_data::{SaveDataError, TrainerID};
+use crate::data_structure::trainer::encode_name;
+use crate::misc::pk_species;
+
+const HALL_OF_FAME_TEAMS: usize = 50;
+const HALL_OF_FAME_TEAM_SIZE: usize = 6;
+const HALL_OF_FAME_MON_SIZE: usize = 20;
+const NICKNAME_SIZE: usize = 10;
+const HALL_OF_FAME_TEAM_DATA_SIZE: usize = HALL_OF_FAME_TEAM_SIZE * HALL_OF_FAME_MON_SIZE;
+/// Bytes of the two sectors used by the records.
+const HALL_OF_FAME_DATA_SIZE: usize = HALL_OF_FAME_TEAMS * HALL_OF_FAME_TEAM_DATA_SIZE;
+
+/// Team registered when entering the Hall of Fame.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct HallOfFameTeam {
+    pub members: Vec<HallOfFameMon>,
+}
+
+/// Pokémon of a Hall of Fame team.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct HallOfFameMon {
+    ot_id: [u8; 4],
+    personality_value: u32,
+    species: u16,
+    level: u8,
+    nickname: [u8; NICKNAME_SIZE],
+}
+
+impl HallOfFameMon {
+    fn new(buffer: &[u8]) -> Self {
+        let species_level = LittleEndian::read_u16(&buffer[0x0008..0x000A]);
+
+        let mut ot_id = [0; 4];
+        ot_id.copy_from_slice(&buffer[0x0000..0x0004]);
+        let mut nickname = [0; NICKNAME_SIZE];
+        nickname.copy_from_slice(&buffer[0x000A..0x0014]);
+
+        HallOfFameMon {
+            ot_id,
+            personality_value: LittleEndian::read_u32(&buffer[0x0004..0x0008]),
+            species: species_level & 0x01FF,
+            level: (species_level >> 9) as u8,
+            nickname,
+        }
+    }
+
+    fn raw_data(&self) -> [u8; HALL_OF_FAME_MON_SIZE] {
+        let mut buffer = [0; HALL_OF_FAME_MON_SIZE];
+        let species_level = (self.species & 0x01FF) | ((self.level as u16 & 0x7F) << 9);
+
+        buffer[0x0000..0x0004].copy_from_slice(&self.ot_id);
+        LittleEndian::write_u32(&mut buffer[0x0004..0x0008], self.personality_value);
+        LittleEndian::write_u16(&mut buffer[0x0008..0x000A], species_level);
+        buffer[0x000A..0x0014].copy_from_slice(&self.nickname);
+
+        buffer
+    }
+
+    pub fn ot_id(&self) -> TrainerID {
+        self.ot_id.into()
+    }
+
+    pub fn set_ot_id(&mut self, ot_id: TrainerID) {
+        self.ot_id = ot_id.into();
+    }
+
+    pub fn personality_value(&self) -> u32 {
+        self.personality_value
+    }
+
+    pub fn set_personality_value(&mut self, personality_value: u32) {
+        self.personality_value = personality_value;
+    }
+
+    pub fn is_shiny(&self) -> bool {
+        self.ot_id().is_shiny(self.personality_value)
+    }
+
+    pub fn species(&self) -> String {
+        let dex_num = self.nat_dex_number();
+
+        if dex_num != 0 {
+            pk_species(dex_num).unwrap_or_default()
+        } else {
+            String::from("")
+        }
+    }
+
+    /// The nickname is left as it is, use `set_nickname` to rename the Pokémon.
+    pub fn set_species(&mut self, species: &str) -> Result<(), SaveDataError> {
+        self.species = species_index(species)
+            .map_err(|_| SaveDataError::UnknownSpecies(species.to_string()))?;
+
+        Ok(())
+    }
+
+    pub fn nat_dex_number(&self) -> u16 {
+        nat_dex_number(self.species)
+    }
+
+    pub fn level(&self) -> u8 {
+        self.level
+    }
+
+    /// Levels above 127 do not fit the 7 bits the game keeps for them and are capped.
+    pub fn set_level(&mut self, level: u8) {
+        self.level = level.min(0x7F);
+    }
+
+    pub fn nickname(&self) -> String {
+        let nickname = &self
+            .nickname
+            .iter()
+            .map(|c| get_char(*c as usize))
+            .collect::<Vec<&str>>();
+
+        let nickname = nickname.join("");
+        let nickname = nickname.split(' ').next().unwrap();
+
+        nickname.to_string()
+    }
+
+    /// Nicknames longer than 10 characters or using characters the games can't show are rejected.
+    pub fn set_nickname(&mut self, nickname: &str) -> Result<(), SaveDataError> {
+        self.nickname
+            .copy_from_slice(&encode_name(nickname, NICKNAME_SIZE)?);
+
+        Ok(())
+    }
+
+    fn is_empty(&self) -> bool {
+        self.species == 0
+    }
+}
+
+impl From<&Pokemon> for HallOfFameMon {
+    fn from(pokemon: &Pokemon) -> Self {
+        HallOfFameMon {
+            ot_id: pokemon.ot_id().into(),
+            personality_value: pokemon.personality_value(),
+            species: pokemon.species_id(),
+            level: pokemon.level().min(0x7F),
+            nickname: pokemon.raw_nickname(),
+        }
+    }
+}
+
+/// Reads the teams from the data of both sectors, stopping at the first empty team.
+pub(crate) fn decode(data: &[u8]) -> Vec<HallOfFameTeam> {
+    data[..HALL_OF_FAME_DATA_SIZE]
+        .chunks(HALL_OF_FAME_TEAM_DATA_SIZE)
+        .map(|team| HallOfFameTeam {
+            members: team
+                .chunks(HALL_OF_FAME_MON_SIZE)
+                .map(HallOfFameMon::new)
+                .take_while(|mon| !mon.is_empty())
+                .collect(),
+        })
+        .take_while(|team| !team.members.is_empty())
+        .collect()
+}
+
+/// Writes the teams as the data of both sectors, teams without members are left out.
+pub(crate) fn encode(teams: &[HallOfFameTeam]) -> Result<Vec<u8>, SaveDataError> {
+    let teams: Vec<&HallOfFameTeam> = teams
+        .iter()
+        .filter(|team| !team.members.is_empty())
+        .collect();
+
+    if teams.len() > HALL_OF_FAME_TEAMS {
+        return Err(SaveDataError::InvalidDataLength {
+            expected: HALL_OF_FAME_TEAMS,
+            found: teams.len(),
+        });
+    }
+
+    let mut data = vec![0; HALL_OF_FAME_DATA_SIZE];
+
+    for (team, buffer) in teams
+        .iter()
+        .zip(data.chunks_mut(HALL_OF_FAME_TEAM_DATA_SIZE))
+    {
+        if team.members.len() > HALL_OF_FAME_TEAM_SIZE {
+            return Err(SaveDataError::InvalidDataLength {
+                expected: HALL_OF_FAME_TEAM_SIZE,
+                found: team.members.len(),
+            });
+        }
+
+        for (mon, buffer) in team
+            .members
+            .iter()
+            .zip(buffer.chunks_mut(HALL_OF_FAME_MON_SIZE))
+        {
+            buffer.copy_from_slice(&mon.raw_data());
+        }
+    }
+
+    Ok(data)
+}
@@ -0,0 +1,278 @@
+//! Items of the bag pockets and of the PC item storage.
+//!
+//! Each slot holds the Gen III index of the item and its quantity, empty slots hold zeros.
+//!
+//!| Offset | Size | Contents                              |
+//!|--------|------|---------------------------------------|
+//!| 0x0000 |   2  |  Item index                           |
+//!| 0x0002 |   2  |  Quantity (XORed with the key)        |
+//!
+//! The quantities of the bag are masked with the lower half of the security key, the ones of the
+//! PC item storage never are. Items are kept by index, so items missing from the database still
+//! survive being read and written back.
+//!
+//! The pocket each item belongs in comes from the `type` column of the `Items` table, except for
+//! the key items which are known by index. Items missing from the database may go in any pocket.
+//!
+//! # Example
+//!
+//! ```rust no_run
+//! use pk_edit::{SaveFile, Pocket};
+//! use pk_edit::data_structure::item::Item;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let buffer: Vec<u8> = vec![];
+//! let mut save_file = SaveFile::parse(&buffer)?;
+//!
+//! let mut items = save_file.pocket(Pocket::Items)?;
+//! items[0] = Item::from_name("Rare Candy", 99)?;
+//! save_file.save_pocket(Pocket::Items, &items)?;
+//! # Ok(())
+//! # }
+//! ```
+use std::fmt;
+use std::ops::RangeInclusive;
+
+use crate::data_structure::save_data::{GameVersion, Pocket, SaveDataError};
+use crate::misc::{find_item, item_id_g3, item_type};
+
+const HM01: u16 = 339;
+const HM08: u16 = 346;
+
+/// Key items of Ruby/Sapphire/Emerald, Mach Bike to Devon Scope.
+const HOENN_KEY_ITEMS: RangeInclusive<u16> = 259..=288;
+/// Key items added by FireRed/LeafGreen and Emerald, Oak's Parcel to Old Sea Map.
+const KANTO_KEY_ITEMS: RangeInclusive<u16> = 349..=376;
+
+/// Size of a pocket slot.
+pub(crate) const ITEM_SLOT_SIZE: usize = 4;
+
+/// Item of a pocket slot.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Item {
+    /// Gen III item index, 0 for an empty slot.
+    pub id: u16,
+    pub quantity: u16,
+}
+
+impl Item {
+    pub fn new(id: u16, quantity: u16) -> Self {
+        Item { id, quantity }
+    }
+
+    /// Looks up the item index by its English name.
+    pub fn from_name(name: &str, quantity: u16) -> Result<Self, SaveDataError> {
+        let id = item_id_g3(name).map_err(|_| SaveDataError::UnknownItem(name.to_string()))?;
+        Ok(Item { id, quantity })
+    }
+
+    /// English name of the item, read from the database. "Unknown" for an index missing from it.
+    pub fn name(&self) -> String {
+        find_item(self.id as usize).unwrap_or_else(|_| "Unknown".to_string())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.id == 0
+    }
+}
+
+/// Largest quantity of an item a pocket slot may hold.
+///
+/// Ruby/Sapphire/Emerald stop at 99 in the bag, except for the Berries pocket which holds up to
+/// 999. FireRed/LeafGreen and the PC item storage of every game hold up to 999.
+pub fn max_quantity(version: GameVersion, pocket: Pocket) -> u16 {
+    match (version, pocket) {
+        (_, Pocket::PcItems) => 999,
+        (_, Pocket::Berries) => 999,
+        (GameVersion::FireRedLeafGreen, _) => 999,
+        (GameVersion::RubySapphire | GameVersion::Emerald, _) => 99,
+    }
+}
+
+/// Order of the items of a sorted pocket, see `SaveFile::sort_pocket`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SortOrder {
+    /// Alphabetical order of the English names.
+    Name,
+    /// Order of the item indices.
+    Id,
+    /// Order the games sort the Berries and TM pockets in: item index order, HMs first in the
+    /// FireRed/LeafGreen TM case.
+    Game,
+}
+
+/// Sorts the items of a pocket, empty slots go last. Items with the same position keep their
+/// order.
+pub(crate) fn sort(items: &mut [Item], order: SortOrder, version: GameVersion, pocket: Pocket) {
+    let hms_first = version == GameVersion::FireRedLeafGreen && pocket == Pocket::Tms;
+
+    match order {
+        SortOrder::Name => items.sort_by_cached_key(|item| (item.is_empty(), item.name())),
+        SortOrder::Id => items.sort_by_key(|item| (item.is_empty(), item.id)),
+        SortOrder::Game => items.sort_by_key(|item| {
+            let is_hm = (HM01..=HM08).contains(&item.id);
+            (item.is_empty(), !(hms_first && is_hm), item.id)
+        }),
+    }
+}
+
+/// Pocket an item belongs in, `None` for an item missing from the database.
+///
+/// The database files the Root and Claw Fossils as general items and lacks several key items,
+/// so the key items are matched by index first.
+pub fn item_pocket(id: u16) -> Option<Pocket> {
+    if HOENN_KEY_ITEMS.contains(&id) || KANTO_KEY_ITEMS.contains(&id) {
+        return Some(Pocket::Key);
+    }
+
+    let pocket = match item_type(id as usize).ok()?.as_str() {
+        "Pokeballs" => Pocket::Pokeballs,
+        "Berries" => Pocket::Berries,
+        "Machines" => Pocket::Tms,
+        "Key Items" => Pocket::Key,
+        _ => Pocket::Items,
+    };
+
+    Some(pocket)
+}
+
+/// Problem found in the items of a pocket, see `SaveFile::validate_pocket`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PocketViolation {
+    /// Item belonging in another pocket.
+    WrongPocket {
+        slot: usize,
+        item: Item,
+        pocket: Pocket,
+    },
+    /// Item already held by an earlier slot with room left.
+    Duplicate {
+        slot: usize,
+        item: Item,
+        first_slot: usize,
+    },
+    /// Quantity above the limit of the pocket.
+    Quantity { slot: usize, item: Item, max: u16 },
+    /// More items than the pocket has slots.
+    Capacity { slots: usize, found: usize },
+}
+
+impl fmt::Display for PocketViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PocketViolation::WrongPocket { slot, item, pocket } => write!(
+                f,
+                "slot {slot}: item {} belongs in the {pocket:?} pocket",
+                item.id
+            ),
+            PocketViolation::Duplicate {
+                slot,
+                item,
+                first_slot,
+            } => write!(
+                f,
+                "slot {slot}: item {} already in slot {first_slot}",
+                item.id
+            ),
+            PocketViolation::Quantity { slot, item, max } => {
+                write!(f, "slot {slot}: quantity {} above {max}", item.quantity)
+            }
+            PocketViolation::Capacity { slots, found } => {
+                write!(f, "{found} items for {slots} slots")
+            }
+        }
+    }
+}
+
+/// Lists the violations in a single line, for error messages.
+pub(crate) fn describe(violations: &[PocketViolation]) -> String {
+    violations
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// Checks the items of a pocket of `slots` slots. Empty slots are skipped.
+pub(crate) fn validate(
+    pocket: Pocket,
+    items: &[Item],
+    slots: usize,
+    max_quantity: u16,
+) -> Vec<PocketViolation> {
+    let mut violations = vec![];
+
+    if items.len() > slots {
+        violations.push(PocketViolation::Capacity {
+            slots,
+            found: items.len(),
+        });
+    }
+
+    for (slot, item) in items.iter().enumerate() {
+        if item.is_empty() {
+            continue;
+        }
+
+        // The PC item storage takes items of any pocket
+        if pocket != Pocket::PcItems {
+            if let Some(item_pocket) = item_pocket(item.id) {
+                if item_pocket != pocket {
+                    violations.push(PocketViolation::WrongPocket {
+                        slot,
+                        item: *item,
+                        pocket: item_pocket,
+                    });
+                }
+            }
+        }
+
+        // The games only start a new slot once the previous one is full
+        if let Some(first_slot) = items[..slot]
+            .iter()
+            .position(|other| other.id == item.id && other.quantity < max_quantity)
+        {
+            violations.push(PocketViolation::Duplicate {
+                slot,
+                item: *item,
+                first_slot,
+            });
+        }
+
+        if item.quantity > max_quantity {
+            violations.push(PocketViolation::Quantity {
+                slot,
+                item: *item,
+                max: max_quantity,
+            });
+        }
+    }
+
+    violations
+}
+
+/// Adds up the quantities of each item, in order of first appearance, and splits them into full
+/// slots the way the games do. Empty slots are dropped.
+pub(crate) fn merge(items: &[Item], max_quantity: u16) -> Vec<Item> {
+    let mut totals: Vec<(u16, u32)> = vec![];
+
+    for item in items.iter().filter(|item| !item.is_empty()) {
+        match totals.iter_mut().find(|(id, _)| *id == item.id) {
+            Some((_, total)) => *total += item.quantity as u32,
+            None => totals.push((item.id, item.quantity as u32)),
+        }
+    }
+
+    let mut merged = vec![];
+
+    for (id, mut total) in totals {
+        while total > max_quantity as u32 {
+            merged.push(Item::new(id, max_quantity));
+            total -= max_quantity as u32;
+        }
+
+        merged.push(Item::new(id, total as u16));
+    }
+
+    merged
+}
@@ -0,0 +1,251 @@
+//! Location of the fields stored in the game save sections for each game version.
+//!
+//! Trainer Info (section 0) holds the data the games call SaveBlock2. Sections 1 to 4 (Team / items,
+//! Game State, Misc Data and Rival info) hold SaveBlock1, split into chunks of 3968 bytes:
+//!
+//!| Section |  SaveBlock1 offsets  |
+//!|---------|----------------------|
+//!|   01    | 0x0000 - 0x0F7F      |
+//!|   02    | 0x0F80 - 0x1EFF      |
+//!|   03    | 0x1F00 - 0x2E7F      |
+//!|   04    | 0x2E80 - 0x3DFF      |
+//!
+//! Fields of SaveBlock1 may start near the end of a section and continue at the beginning of the
+//! next one, such as the FireRed/LeafGreen event flags. Their `size` then goes past the data size
+//! of `section`.
+//!
+//! `Layout` names no field of Misc Data (section 3) yet. Its data can still be reached with
+//! `Field::save_block_1` and an offset from 0x1F00 to 0x2E7F.
+//!
+//! # Example
+//!
+//! ```rust no_run
+//! use pk_edit::SaveFile;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let buffer: Vec<u8> = vec![];
+//! let save_file = SaveFile::parse(&buffer)?;
+//! let layout = save_file.game_version()?.layout();
+//!
+//! let money = save_file.field(layout.money)?;
+//! # Ok(())
+//! # }
+//! ```
+use crate::data_structure::save_data::{Pocket, SectionID};
+
+/// Size of the SaveBlock1 chunk held by each of the sections 1 to 4.
+const SAVE_BLOCK_1_CHUNK_SIZE: usize = 0xF80; // 3968 bytes
+
+/// Location of a field: the section holding it, its offset in the section data and its size in bytes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Field {
+    pub section: SectionID,
+    pub offset: usize,
+    pub size: usize,
+}
+
+impl Field {
+    /// Field of the Trainer Info section.
+    pub const fn trainer_info(offset: usize, size: usize) -> Field {
+        Field {
+            section: SectionID::TrainerInfo,
+            offset,
+            size,
+        }
+    }
+
+    /// Field of SaveBlock1, from its offset in SaveBlock1 rather than in the section.
+    pub const fn save_block_1(offset: usize, size: usize) -> Field {
+        let section = match offset / SAVE_BLOCK_1_CHUNK_SIZE {
+            0 => SectionID::TeamItems,
+            1 => SectionID::GameState,
+            2 => SectionID::MiscData,
+            3 => SectionID::RivalInfo,
+            _ => SectionID::NA,
+        };
+
+        Field {
+            section,
+            offset: offset % SAVE_BLOCK_1_CHUNK_SIZE,
+            size,
+        }
+    }
+}
+
+/// Fields of the game save of one game version.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Layout {
+    // Trainer Info
+    pub player_name: Field,
+    pub player_gender: Field,
+    pub trainer_id: Field,
+    pub play_time: Field,
+    pub game_code: Field,
+    /// `None` for Ruby/Sapphire, which do not mask any value.
+    pub security_key: Option<Field>,
+    pub national_dex_magic: Field,
+    pub pokedex_owned: Field,
+    pub pokedex_seen: Field,
+    pub pokedex_unown_personality: Field,
+    pub pokedex_spinda_personality: Field,
+
+    // Team / items
+    pub party_count: Field,
+    pub party: Field,
+    pub money: Field,
+    pub coins: Field,
+    pub pc_items: Field,
+    pub items: Field,
+    pub key_items: Field,
+    pub pokeballs: Field,
+    pub tms: Field,
+    pub berries: Field,
+    /// Copies of `pokedex_seen`, the second one in Rival info.
+    pub pokedex_seen_copies: [Field; 2],
+    /// Day Care slots, in the format of the PC boxes. Ruby/Sapphire keep the mail and step
+    /// counts after both Pokémon, the other games after each one.
+    pub daycare: [Field; 2],
+
+    // Game State
+    pub flags: Field,
+    pub vars: Field,
+    pub game_stats: Field,
+
+    // Rival info
+    /// `None` for Ruby/Sapphire/Emerald, which do not let the player name the rival.
+    pub rival_name: Option<Field>,
+}
+
+impl Layout {
+    /// Field holding the slots of a bag pocket or of the PC item storage.
+    pub fn pocket(&self, pocket: Pocket) -> Field {
+        match pocket {
+            Pocket::Items => self.items,
+            Pocket::Pokeballs => self.pokeballs,
+            Pocket::Berries => self.berries,
+            Pocket::Tms => self.tms,
+            Pocket::Key => self.key_items,
+            Pocket::PcItems => self.pc_items,
+        }
+    }
+}
+
+pub const RUBY_SAPPHIRE: Layout = Layout {
+    player_name: Field::trainer_info(0x0000, 7),
+    player_gender: Field::trainer_info(0x0008, 1),
+    trainer_id: Field::trainer_info(0x000A, 4),
+    play_time: Field::trainer_info(0x000E, 5),
+    game_code: Field::trainer_info(0x00AC, 4),
+    security_key: None,
+
+    national_dex_magic: Field::trainer_info(0x001A, 1),
+    pokedex_owned: Field::trainer_info(0x0028, 52),
+    pokedex_seen: Field::trainer_info(0x005C, 52),
+    pokedex_unown_personality: Field::trainer_info(0x001C, 4),
+    pokedex_spinda_personality: Field::trainer_info(0x0020, 4),
+
+    party_count: Field::save_block_1(0x0234, 4),
+    party: Field::save_block_1(0x0238, 600),
+    money: Field::save_block_1(0x0490, 4),
+    coins: Field::save_block_1(0x0494, 2),
+    pc_items: Field::save_block_1(0x0498, 200),
+    items: Field::save_block_1(0x0560, 80),
+    key_items: Field::save_block_1(0x05B0, 80),
+    pokeballs: Field::save_block_1(0x0600, 64),
+    tms: Field::save_block_1(0x0640, 256),
+    berries: Field::save_block_1(0x0740, 184),
+    pokedex_seen_copies: [
+        Field::save_block_1(0x0938, 52),
+        Field::save_block_1(0x3A8C, 52),
+    ],
+    daycare: [
+        Field::save_block_1(0x2F9C, 80),
+        Field::save_block_1(0x2FEC, 80),
+    ],
+
+    flags: Field::save_block_1(0x1220, 288),
+    vars: Field::save_block_1(0x1340, 512),
+    game_stats: Field::save_block_1(0x1540, 256),
+
+    rival_name: None,
+};
+
+pub const EMERALD: Layout = Layout {
+    player_name: Field::trainer_info(0x0000, 7),
+    player_gender: Field::trainer_info(0x0008, 1),
+    trainer_id: Field::trainer_info(0x000A, 4),
+    play_time: Field::trainer_info(0x000E, 5),
+    game_code: Field::trainer_info(0x00AC, 4),
+    security_key: Some(Field::trainer_info(0x00AC, 4)),
+
+    national_dex_magic: Field::trainer_info(0x001A, 1),
+    pokedex_owned: Field::trainer_info(0x0028, 52),
+    pokedex_seen: Field::trainer_info(0x005C, 52),
+    pokedex_unown_personality: Field::trainer_info(0x001C, 4),
+    pokedex_spinda_personality: Field::trainer_info(0x0020, 4),
+
+    party_count: Field::save_block_1(0x0234, 4),
+    party: Field::save_block_1(0x0238, 600),
+    money: Field::save_block_1(0x0490, 4),
+    coins: Field::save_block_1(0x0494, 2),
+    pc_items: Field::save_block_1(0x0498, 200),
+    items: Field::save_block_1(0x0560, 120),
+    key_items: Field::save_block_1(0x05D8, 120),
+    pokeballs: Field::save_block_1(0x0650, 64),
+    tms: Field::save_block_1(0x0690, 256),
+    berries: Field::save_block_1(0x0790, 184),
+    pokedex_seen_copies: [
+        Field::save_block_1(0x0988, 52),
+        Field::save_block_1(0x3B24, 52),
+    ],
+    daycare: [
+        Field::save_block_1(0x3030, 80),
+        Field::save_block_1(0x30BC, 80),
+    ],
+
+    flags: Field::save_block_1(0x1270, 300),
+    vars: Field::save_block_1(0x139C, 512),
+    game_stats: Field::save_block_1(0x159C, 256),
+
+    rival_name: None,
+};
+
+pub const FIRE_RED_LEAF_GREEN: Layout = Layout {
+    player_name: Field::trainer_info(0x0000, 7),
+    player_gender: Field::trainer_info(0x0008, 1),
+    trainer_id: Field::trainer_info(0x000A, 4),
+    play_time: Field::trainer_info(0x000E, 5),
+    game_code: Field::trainer_info(0x00AC, 4),
+    security_key: Some(Field::trainer_info(0x0F20, 4)),
+
+    national_dex_magic: Field::trainer_info(0x001B, 1),
+    pokedex_owned: Field::trainer_info(0x0028, 52),
+    pokedex_seen: Field::trainer_info(0x005C, 52),
+    pokedex_unown_personality: Field::trainer_info(0x001C, 4),
+    pokedex_spinda_personality: Field::trainer_info(0x0020, 4),
+
+    party_count: Field::save_block_1(0x0034, 4),
+    party: Field::save_block_1(0x0038, 600),
+    money: Field::save_block_1(0x0290, 4),
+    coins: Field::save_block_1(0x0294, 2),
+    pc_items: Field::save_block_1(0x0298, 120),
+    items: Field::save_block_1(0x0310, 168),
+    key_items: Field::save_block_1(0x03B8, 120),
+    pokeballs: Field::save_block_1(0x0430, 52),
+    tms: Field::save_block_1(0x0464, 232),
+    berries: Field::save_block_1(0x054C, 172),
+    pokedex_seen_copies: [
+        Field::save_block_1(0x05F8, 52),
+        Field::save_block_1(0x3A18, 52),
+    ],
+    daycare: [
+        Field::save_block_1(0x2F80, 80),
+        Field::save_block_1(0x300C, 80),
+    ],
+
+    flags: Field::save_block_1(0x0EE0, 288),
+    vars: Field::save_block_1(0x1000, 512),
+    game_stats: Field::save_block_1(0x1200, 256),
+
+    rival_name: Some(Field::save_block_1(0x3A4C, 8)),
+};
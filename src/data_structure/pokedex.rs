@@ -0,0 +1,212 @@
+//! Pokédex seen and owned flags.
+//!
+//! Trainer Info holds one bit per species, in National Pokédex order, for the species owned and
+//! the species seen. The games keep two more copies of the seen flags in SaveBlock1, all three
+//! must agree or the Pokédex is treated as tampered with.
+//!
+//!| Offset | Size | Contents                   |
+//!|--------|------|----------------------------|
+//!| 0x0018 |   1  |  Order                     |
+//!| 0x0019 |   1  |  Mode                      |
+//!| 0x001A |   1  |  National Pokédex (RS/E)   |
+//!| 0x001B |   1  |  National Pokédex (FrLg)   |
+//!| 0x001C |   4  |  Unown personality value   |
+//!| 0x0020 |   4  |  Spinda personality value  |
+//!| 0x0028 |  52  |  Owned                     |
+//!| 0x005C |  52  |  Seen                      |
+//!
+//! The personality values of the first Unown and Spinda registered decide the form and spot
+//! pattern shown by the Pokédex for those species.
+//!
+//! Unlocking the National Pokédex takes a magic value in Trainer Info, an event flag and a game
+//! variable, see `SaveFile::unlock_national_dex`.
+use crate::data_structure::flags;
+use crate::data_structure::pokemon::Pokemon;
+use crate::data_structure::save_data::{GameVersion, SaveDataError};
+use crate::data_structure::vars::{EmeraldVar, FireRedLeafGreenVar, RubySapphireVar};
+
+/// Number of species of the National Pokédex.
+pub const NATIONAL_DEX_COUNT: u16 = 386;
+const UNOWN: u16 = 201;
+const SPINDA: u16 = 327;
+
+/// Copy of the Pokédex flags of a save file, see `SaveFile::pokedex`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pokedex {
+    owned: Vec<u8>,
+    seen: Vec<u8>,
+    unown_personality_value: u32,
+    spinda_personality_value: u32,
+}
+
+/// Seen and owned state of a species.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PokedexEntry {
+    pub nat_dex_number: u16,
+    pub seen: bool,
+    pub owned: bool,
+}
+
+impl Pokedex {
+    pub(crate) fn new(
+        owned: Vec<u8>,
+        seen: Vec<u8>,
+        unown_personality_value: u32,
+        spinda_personality_value: u32,
+    ) -> Self {
+        Pokedex {
+            owned,
+            seen,
+            unown_personality_value,
+            spinda_personality_value,
+        }
+    }
+
+    pub(crate) fn owned_data(&self) -> &[u8] {
+        &self.owned
+    }
+
+    pub(crate) fn seen_data(&self) -> &[u8] {
+        &self.seen
+    }
+
+    pub fn is_seen(&self, nat_dex_number: u16) -> Result<bool, SaveDataError> {
+        let (byte, bit) = position(nat_dex_number)?;
+        Ok(self.seen[byte] & bit != 0)
+    }
+
+    pub fn is_owned(&self, nat_dex_number: u16) -> Result<bool, SaveDataError> {
+        let (byte, bit) = position(nat_dex_number)?;
+        Ok(self.owned[byte] & bit != 0)
+    }
+
+    /// A species no longer seen is no longer owned either.
+    pub fn set_seen(&mut self, nat_dex_number: u16, seen: bool) -> Result<(), SaveDataError> {
+        let (byte, bit) = position(nat_dex_number)?;
+
+        if seen {
+            self.seen[byte] |= bit;
+        } else {
+            self.seen[byte] &= !bit;
+            self.owned[byte] &= !bit;
+        }
+
+        Ok(())
+    }
+
+    /// A species owned is seen as well.
+    pub fn set_owned(&mut self, nat_dex_number: u16, owned: bool) -> Result<(), SaveDataError> {
+        let (byte, bit) = position(nat_dex_number)?;
+
+        if owned {
+            self.owned[byte] |= bit;
+            self.seen[byte] |= bit;
+        } else {
+            self.owned[byte] &= !bit;
+        }
+
+        Ok(())
+    }
+
+    /// Personality value deciding the Unown form shown by the Pokédex.
+    pub fn unown_personality_value(&self) -> u32 {
+        self.unown_personality_value
+    }
+
+    pub fn set_unown_personality_value(&mut self, personality_value: u32) {
+        self.unown_personality_value = personality_value;
+    }
+
+    /// Personality value deciding the Spinda spot pattern shown by the Pokédex.
+    pub fn spinda_personality_value(&self) -> u32 {
+        self.spinda_personality_value
+    }
+
+    pub fn set_spinda_personality_value(&mut self, personality_value: u32) {
+        self.spinda_personality_value = personality_value;
+    }
+
+    /// Copies the personality value of an Unown or a Spinda, so the Pokédex shows its form or
+    /// spot pattern. Returns `false`, leaving the Pokédex untouched, for any other species.
+    pub fn sync_personality_value(&mut self, pokemon: &Pokemon) -> bool {
+        match pokemon.nat_dex_number() {
+            UNOWN => self.unown_personality_value = pokemon.personality_value(),
+            SPINDA => self.spinda_personality_value = pokemon.personality_value(),
+            _ => return false,
+        }
+
+        true
+    }
+
+    /// Marks every species of the National Pokédex as owned.
+    pub fn complete(&mut self) {
+        for nat_dex_number in 1..=NATIONAL_DEX_COUNT {
+            if let Ok((byte, bit)) = position(nat_dex_number) {
+                self.owned[byte] |= bit;
+                self.seen[byte] |= bit;
+            }
+        }
+    }
+
+    pub fn seen_count(&self) -> usize {
+        self.entries().iter().filter(|entry| entry.seen).count()
+    }
+
+    pub fn owned_count(&self) -> usize {
+        self.entries().iter().filter(|entry| entry.owned).count()
+    }
+
+    /// State of every species of the National Pokédex.
+    pub fn entries(&self) -> Vec<PokedexEntry> {
+        (1..=NATIONAL_DEX_COUNT)
+            .map(|nat_dex_number| PokedexEntry {
+                nat_dex_number,
+                seen: self.is_seen(nat_dex_number).unwrap_or_default(),
+                owned: self.is_owned(nat_dex_number).unwrap_or_default(),
+            })
+            .collect()
+    }
+}
+
+fn position(nat_dex_number: u16) -> Result<(usize, u8), SaveDataError> {
+    if nat_dex_number == 0 || nat_dex_number > NATIONAL_DEX_COUNT {
+        return Err(SaveDataError::ValueOutOfRange {
+            value: nat_dex_number as u32,
+            max: NATIONAL_DEX_COUNT as u32,
+        });
+    }
+
+    let index = nat_dex_number - 1;
+    Ok((index as usize / 8, 1 << (index % 8)))
+}
+
+/// Values written to unlock the National Pokédex.
+pub(crate) struct NationalDexUnlock {
+    pub magic: u8,
+    pub flag: u16,
+    pub var: u16,
+    pub var_value: u16,
+}
+
+pub(crate) fn national_dex_unlock(version: GameVersion) -> NationalDexUnlock {
+    match version {
+        GameVersion::RubySapphire => NationalDexUnlock {
+            magic: 0xDA,
+            flag: flags::national_dex_flag(version),
+            var: RubySapphireVar::NationalDex.into(),
+            var_value: 0x0302,
+        },
+        GameVersion::Emerald => NationalDexUnlock {
+            magic: 0xDA,
+            flag: flags::national_dex_flag(version),
+            var: EmeraldVar::NationalDex.into(),
+            var_value: 0x0302,
+        },
+        GameVersion::FireRedLeafGreen => NationalDexUnlock {
+            magic: 0xB9,
+            flag: flags::national_dex_flag(version),
+            var: FireRedLeafGreenVar::NationalDex.into(),
+            var_value: 0x6258,
+        },
+    }
+}
@@ -7,11 +7,12 @@
 //!
 //! # Example Usage
 //! ## Modifying a Pokémon's Attributes
-//! ```rust
+//! ```rust no_run
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! use std::fs::File;
 //! use std::io::BufReader;
 //! use pk_edit::SaveFile;
-//! use pk_edit::StorageType;
+//! use pk_edit::SlotRef;
 //! use std::io::Read;
 //!
 //! let mut buffer = Vec::new();
@@ -19,23 +20,32 @@
 //! let mut buf_reader = BufReader::new(file);
 //! buf_reader.read_to_end(&mut buffer)?;
 //!
-//! let save_file: SaveFile = SaveFile::new(&buffer);
-//! let mut pokemon = save_file.pc_box(0)[0];
+//! let mut save_file: SaveFile = SaveFile::parse(&buffer)?;
+//! let mut pokemon = save_file.pc_box(0)?[0];
 //!
 //! pokemon.set_friendship(100);
 //! pokemon.set_level(50);
-//! save_file.save_pokemon(StorageType::PC, pokemon)?;
+//! save_file.save_pokemon(SlotRef::Box(0, 0), pokemon)?;
+//! # Ok(())
+//! # }
 //! ```
 //! ## Viewing Pokémon Data
-//! ```rust
+//! ```rust no_run
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # use std::fs::File;
+//! # use std::io::BufReader;
+//! # use std::io::Read;
+//! # use pk_edit::SaveFile;
 //! let mut buffer = Vec::new();
 //! let file = File::open("~/Pokemon - Emerald Version/Pokemon - Emerald Version (U).sav")?;
 //! let mut buf_reader = BufReader::new(file);
 //! buf_reader.read_to_end(&mut buffer)?;
 //!
-//! let save_file: SaveFile = SaveFile::new(&buffer);
-//! let pokemon = save_file.pc_box(0)[0];
+//! let save_file: SaveFile = SaveFile::parse(&buffer)?;
+//! let pokemon = save_file.pc_box(0)?[0];
 //! println!("Level: {}, Friendship: {}", pokemon.level(), pokemon.friendship());
+//! # Ok(())
+//! # }
 //! ```
 use byteorder::{ByteOrder, LittleEndian};
 use rand::Rng;
@@ -43,7 +53,7 @@ use std::fmt;
 use thiserror::Error;
 
 use crate::data_structure::character_set::{get_char, get_code};
-use crate::data_structure::save_data::TrainerID;
+use crate::data_structure::save_data::{SlotRef, TrainerID};
 use crate::misc::{
     evolution, base_stats, find_move, ability, find_item, gender_ratio, growth_rate, hidden_ability, item_id_g3, move_data,
     nat_dex_num, pk_species, typing, EXPERIENCE_TABLE, GENDER_THRESHOLD, /*MOVES,*/ NATURE,
@@ -72,6 +82,7 @@ pub struct Evolution {
 }
 
 impl Evolution {
+    #[allow(clippy::manual_ok_err)]
     pub fn prev_level(&mut self) -> Option<u8> {
         if let Some(prev) = &self.prev {
             let level_str = &prev[1].replace("Level ", "");
@@ -90,6 +101,7 @@ impl Evolution {
 #[derive(Debug, Default, Copy, Clone)]
 pub struct Pokemon {
     offset: usize,
+    slot: Option<SlotRef>,
     personality_value: [u8; 4],
     ot_id: [u8; 4],
     nickname: [u8; 10],
@@ -162,6 +174,7 @@ impl Pokemon {
 
         let mut pokemon = Pokemon {
             offset,
+            slot: None,
             personality_value,
             ot_id,
             nickname,
@@ -180,10 +193,25 @@ impl Pokemon {
         pokemon
     }
 
+    /// Offset the Pokémon was read from: in the save file for party and Day Care members, in the
+    /// PC Buffer data for PC box members.
+    #[deprecated(
+        since = "0.2.0",
+        note = "the offset depends on where the Pokémon is stored, use `Pokemon::slot`"
+    )]
     pub fn offset(&self) -> usize {
         self.offset
     }
 
+    /// Slot the Pokémon was read from, `None` for a Pokémon not read from a save file.
+    pub fn slot(&self) -> Option<SlotRef> {
+        self.slot
+    }
+
+    pub(crate) fn set_slot(&mut self, slot: SlotRef) {
+        self.slot = Some(slot);
+    }
+
     pub fn ot_id(&self) -> TrainerID {
         self.ot_id.into()
     }
@@ -212,6 +240,10 @@ impl Pokemon {
         nickname.to_string()
     }
 
+    pub(crate) fn raw_nickname(&self) -> [u8; 10] {
+        self.nickname
+    }
+
     fn set_nickname(&mut self, nickname: &str) {
         let name: Vec<u8> = format!("{: <10}", nickname)
             .chars()
@@ -245,6 +277,7 @@ impl Pokemon {
         LittleEndian::read_u16(&self.checksum)
     }
 
+    #[allow(clippy::manual_unwrap_or_default)]
     pub fn species(&self) -> String {
         let dex_num = self.nat_dex_number();
 
@@ -264,16 +297,7 @@ impl Pokemon {
             self.set_nickname(&species.to_uppercase());
         }
 
-        let mut id = match nat_dex_num(species) {
-            Ok(id) => id,
-            Err(_) => return Err(PokemonError::UnknownSpecies(species.to_string())),
-        };
-
-        if id == 0 {
-            id = 412;
-        } else if id >= 252 {
-            id = SPECIES[(id as usize).saturating_sub(251)];
-        }
+        let id = species_index(species)?;
 
         let offset = self.pokemon_data.growth_offset;
         self.pokemon_data.data[offset..offset + 2].copy_from_slice(&id.to_le_bytes());
@@ -286,22 +310,7 @@ impl Pokemon {
     }
 
     pub fn nat_dex_number(&self) -> u16 {
-        let species = self.species_id();
-        if species == 412 {
-            return 0;
-        }
-
-        if species >= 277 {
-            return (SPECIES
-                .iter()
-                .position(|&x| x == species)
-                .unwrap()
-                .saturating_add(251))
-            .try_into()
-            .unwrap();
-        }
-
-        species
+        nat_dex_number(self.species_id())
     }
 
     pub fn experience(&self) -> u32 {
@@ -313,6 +322,7 @@ impl Pokemon {
         gender_from_p(self.personality_value(), self.nat_dex_number())
     }
 
+    #[allow(clippy::manual_unwrap_or_default)]
     pub fn level(&self) -> u8 {
         let mut level: u32 = 0;
 
@@ -333,6 +343,7 @@ impl Pokemon {
         level as u8
     }
 
+    #[allow(clippy::manual_unwrap_or_default)]
     pub fn set_level(&mut self, level: u8) {
         let index = self.nat_dex_number();
 
@@ -348,6 +359,7 @@ impl Pokemon {
         self.pokemon_data.data[offset + 4..offset + 8].copy_from_slice(&experience.to_le_bytes());
     }
 
+    #[allow(clippy::manual_ok_err)]
     pub fn typing(&self) -> Option<(String, Option<String>)> {
         if self.is_empty() {
             return None;
@@ -361,6 +373,7 @@ impl Pokemon {
         }
     }
 
+    #[allow(clippy::manual_unwrap_or_default)]
     pub fn ability(&self) -> String {
         let index = self.nat_dex_number();
         let ability_index = self.ability_index();
@@ -378,6 +391,7 @@ impl Pokemon {
         }
     }
 
+    #[allow(clippy::manual_ok_err)]
     pub fn moves(&self) -> Vec<(String, String, u8, u8)> {
         let offset = self.pokemon_data.attacks_offset;
         let move1_index =
@@ -630,6 +644,10 @@ impl Pokemon {
         order_data_substructure(value % 24, &mut self.pokemon_data);
     }
 
+    pub fn is_shiny(&self) -> bool {
+        self.ot_id().is_shiny(self.personality_value())
+    }
+
     pub fn infect_pokerus(&mut self) {
         let offset = self.pokemon_data.miscellaneous_offset;
         let mut pokerus = self.pokemon_data.data[offset..offset + 1][0];
@@ -753,7 +771,7 @@ impl Pokemon {
         (self.personality_value() % 25) as usize
     }
 
-    fn species_id(&self) -> u16 {
+    pub(crate) fn species_id(&self) -> u16 {
         let offset = self.pokemon_data.growth_offset;
         LittleEndian::read_u16(&self.pokemon_data.data[offset..offset + 2])
     }
@@ -1247,6 +1265,7 @@ fn growth_index(growth: &str) -> usize {
     }
 }
 
+#[allow(clippy::manual_unwrap_or_default)]
 fn gender_threshold(dex_num: u16) -> u32 {
     let gender = match gender_ratio(dex_num) {
         Ok(ratio) => ratio,
@@ -1287,6 +1306,39 @@ fn recalc_iv(new_iv: u16) -> u16 {
     }
 }
 
+/// National Pokédex number of a Gen III species index.
+pub(crate) fn nat_dex_number(species: u16) -> u16 {
+    if species == 412 {
+        return 0;
+    }
+
+    if species >= 277 {
+        // Corrupted data can hold ids outside of the table, treat them as empty
+        return SPECIES
+            .iter()
+            .position(|&x| x == species)
+            .map_or(0, |position| position.saturating_add(251) as u16);
+    }
+
+    species
+}
+
+/// Gen III species index of a species name.
+pub(crate) fn species_index(species: &str) -> Result<u16, PokemonError> {
+    let id = match nat_dex_num(species) {
+        Ok(id) => id,
+        Err(_) => return Err(PokemonError::UnknownSpecies(species.to_string())),
+    };
+
+    if id == 0 {
+        Ok(412)
+    } else if id >= 252 {
+        Ok(SPECIES[(id as usize).saturating_sub(251)])
+    } else {
+        Ok(id)
+    }
+}
+
 // generating PIDs is buggy, still don't understand why or how
 pub fn gen_pokemon_from_species(
     mut new_pokemon: Pokemon,
@@ -1348,7 +1400,7 @@ fn find_level(target: u32, index: usize) -> u32 {
 }*/
 
 // generating PIDs is buggy, still don't understand why or how
-fn gen_p(seed: &mut u32) -> u32 {
+fn gen_p(#[allow(unused_variables)] seed: &mut u32) -> u32 {
     let mut t_rng = rand::thread_rng();
     // for some still unknown reason, the program has a strange behaviour que using some ranbom number to generate a PID
     let mut seed: u32 = t_rng.gen();
@@ -13,6 +13,21 @@
 //!| 0x000000 | 57344 |  Game save A  |
 //!| 0x00E000 | 57344 |  Game save B  |
 //!
+//! # File variants
+//!
+//! Not every flash cart or emulator writes the full 128 KiB. 64 KiB files only hold Game save A,
+//! and trimmed files end right after the game save blocks, leaving out the Hall of Fame and the
+//! rest of the flash. Emulators may also append a trailer, such as the 16 bytes of real-time
+//! clock data written by mGBA, or extra bytes written by VisualBoyAdvance. The variant and the
+//! trailer are kept as found, see `SaveFile::variant` and `SaveFile::trailer`.
+//!
+//!|   Variant    |  Size  |       Contents        |
+//!|--------------|--------|-----------------------|
+//!| Full         | 131072 | Whole flash           |
+//!| Half         |  65536 | Game save A and flash |
+//!| Trimmed      | 114688 | Game save A and B     |
+//!| Trimmed half |  57344 | Game save A           |
+//!
 //! # Section format
 //!
 //! All sections contain the same general format: 3968 bytes of data, followed by a footer. All 14 sections must be present exactly once in each game save block.
@@ -62,10 +77,21 @@
 use byteorder::{ByteOrder, LittleEndian};
 use std::convert::From;
 use std::default::Default;
+use std::fmt;
+use std::ops::Range;
 use thiserror::Error;
 
+use crate::data_structure::character_set::CharacterSetError;
+use crate::data_structure::flags::Flags;
+use crate::data_structure::hall_of_fame::{self, HallOfFameTeam};
+use crate::data_structure::item::{self, Item, PocketViolation, SortOrder, ITEM_SLOT_SIZE};
+use crate::data_structure::layout::{self, Field, Layout};
+use crate::data_structure::pokedex::{self, Pokedex};
 use crate::data_structure::pokemon::Pokemon;
-use crate::misc::{find_item, item_id_g3};
+use crate::data_structure::trainer::{
+    self, out_of_range, PlayTime, Trainer, TrainerGender, MAX_COINS, MAX_MONEY,
+};
+use crate::data_structure::vars::Vars;
 
 /// Represents errors that can occur while handling save data.
 #[derive(Error, Debug)]
@@ -90,26 +116,130 @@ pub enum SaveDataError {
     #[error("Checksum mismatch: expected {expected:#X}, found {found:#X}")]
     ChecksumMismatch { expected: u16, found: u16 },
 
+    /// The file is neither a 128 KiB nor a 64 KiB save
+    #[error("Invalid save file size: {0} bytes")]
+    InvalidFileSize(usize),
+
+    /// Section footer without the expected signature
+    #[error("Invalid signature {found:#X} in section at offset {offset:#X}")]
+    InvalidSignature { offset: usize, found: u32 },
+
+    /// Section footer with an unknown section ID
+    #[error("Invalid section ID {found} in section at offset {offset:#X}")]
+    InvalidSectionID { offset: usize, found: u16 },
+
+    /// Section present more than once in the same game save block
+    #[error("Section {0:?} found more than once in the game save block")]
+    DuplicateSection(SectionID),
+
+    /// Sections of the same game save block with different save indices
+    #[error("Save index mismatch: expected {expected}, found {found}")]
+    SaveIndexMismatch { expected: u32, found: u32 },
+
+    /// PC box number out of range
+    #[error("Invalid PC box: {0}")]
+    InvalidBox(usize),
+
+    /// PC box slot out of range
+    #[error("Invalid PC box slot: {0}")]
+    InvalidBoxSlot(usize),
+
+    /// Slot without a Pokémon to move
+    #[error("Slot {0:?} holds no Pokémon")]
+    EmptySlot(SlotRef),
+
+    /// Party slot out of range, or past the last member
+    #[error("Invalid party slot: {0}")]
+    InvalidPartySlot(usize),
+
+    /// Day Care slot out of range
+    #[error("Invalid Day Care slot: {0}")]
+    InvalidDaycareSlot(usize),
+
+    /// No room left in the party
+    #[error("The party is full")]
+    PartyFull,
+
+    /// The party would be left without any member
+    #[error("The last party member can't be moved out of the party")]
+    LastPartyMember,
+
+    /// Game save block not present in the file
+    #[error("Game save block {0:?} not found")]
+    BlockNotFound(SaveBlock),
+
+    /// Hall of Fame not present in the file
+    #[error("Hall of Fame not found")]
+    HallOfFameNotFound,
+
+    /// Value above the largest one the game accepts
+    #[error("Value {value} out of range, the maximum is {max}")]
+    ValueOutOfRange { value: u32, max: u32 },
+
+    /// Pocket items the game would not accept, see `SaveFile::validate_pocket`
+    #[error("Invalid {pocket:?} pocket: {}", item::describe(violations))]
+    InvalidPocket {
+        pocket: Pocket,
+        violations: Vec<PocketViolation>,
+    },
+
+    /// Item name missing from the database
+    #[error("Unknown item: {0}")]
+    UnknownItem(String),
+
+    /// Species name missing from the database
+    #[error("Unknown species: {0}")]
+    UnknownSpecies(String),
+
+    /// Text that cannot be written with the game character set
+    #[error(transparent)]
+    CharacterSet(#[from] CharacterSetError),
+
     /// Unexpected error occurred
     #[error("Unexpected error: {0}")]
     Unexpected(String),
 }
 
-//const SIGNATURE_MAGIC_NUMBER: usize = 0x08012025;
+const SIGNATURE_MAGIC_NUMBER: u32 = 0x08012025;
 const NUMBER_GAME_SAVE_SECTIONS: usize = 14;
 const SECTION_SIZE: usize = 0x1000; // 4096 bytes
 const SECTION_DATA_SIZE: usize = 0x0FF4;
 const PC_BUFFER_SECTION_SIZE: usize = 0xF80; // 3968 bytes
 const PC_BUFFER_I_SECTION_SIZE: usize = 0x7D0; // 2000 bytes
+const TRAINER_INFO_SECTION_SIZE: usize = 0xF2C; // 3884 bytes
+const DEFAULT_SECTION_SIZE: usize = 0xF80; // 3968 bytes
+
+const RS_TRAINER_INFO_SIZE: usize = 0x890; // Bytes of Trainer Info used by Ruby/Sapphire
+const MAX_BERRY_POWDER: u32 = 99999;
+
+const SAVE_FILE_SIZE: usize = 0x20000; // 128 KiB
+const HALF_SAVE_FILE_SIZE: usize = 0x10000; // 64 KiB, only holds Game save A
 
 const GAME_SAVE_A_OFFSET: usize = 0x000000;
-//const GAME_SAVE_A_SIZE: usize = 57344;
+const GAME_SAVE_A_SIZE: usize = 57344;
 
 const GAME_SAVE_B_OFFSET: usize = 0x00E000;
-//const GAME_SAVE_B_SIZE: usize = 57344;
+const GAME_SAVE_B_SIZE: usize = 57344;
 
-//const HALL_FAME_OFFSET: usize = 0x01C000;
-//const HALL_FAME_SIZE: usize = 8192;
+/// Largest trailer accepted after the save data, much larger than any known emulator footer.
+const MAX_TRAILER_SIZE: usize = 0x1000;
+const RTC_TRAILER_SIZE: usize = 16;
+
+const PARTY_SIZE: usize = 6;
+const PARTY_POKEMON_SIZE: usize = 100;
+
+const PC_BOX_COUNT: usize = 14;
+const PC_BOX_SLOTS: usize = 30;
+const PC_POKEMON_SIZE: usize = 80;
+const DAYCARE_SLOTS: usize = 2;
+const PC_BOX_SIZE: usize = PC_BOX_SLOTS * PC_POKEMON_SIZE; // 2400 bytes
+const PC_BOXES_OFFSET: usize = 0x0004;
+const PC_BOX_NAMES_OFFSET: usize = 0x8344;
+const PC_BOX_NAME_SIZE: usize = 9; // 8 characters and the terminator
+const PC_BOX_WALLPAPERS_OFFSET: usize = 0x83C2;
+
+const HALL_FAME_OFFSET: usize = 0x01C000;
+const HALL_FAME_SIZE: usize = 8192;
 
 /// Representation of the Save File.
 ///
@@ -117,378 +247,1573 @@ const GAME_SAVE_B_OFFSET: usize = 0x00E000;
 #[derive(Default, Debug, Clone)]
 pub struct SaveFile {
     game_save_a: [Section; NUMBER_GAME_SAVE_SECTIONS],
-    game_save_b: [Section; NUMBER_GAME_SAVE_SECTIONS],
+    /// `None` for 64 KiB saves, which only hold Game save A.
+    game_save_b: Option<[Section; NUMBER_GAME_SAVE_SECTIONS]>,
     data: Vec<u8>,
     pc_buffer: PCBuffer,
+    /// Block used instead of the most recent one, see `SaveFile::view_block`.
+    selected: Option<SaveBlock>,
+    /// Block picked by the game logic, kept until the blocks are rewritten.
+    selection: Option<BlockSelection>,
+    variant: SaveVariant,
+    /// Bytes found after the save data, written back as they are.
+    trailer: Vec<u8>,
+}
+
+/// Amount of save data held by the file, see the module documentation.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum SaveVariant {
+    /// 128 KiB, the whole flash.
+    #[default]
+    Full,
+    /// 64 KiB, only holding Game save A.
+    Half,
+    /// Both game save blocks without the rest of the flash.
+    Trimmed,
+    /// Game save A alone.
+    TrimmedHalf,
 }
 
+impl SaveVariant {
+    /// Bytes of save data in the file, without the trailer.
+    pub fn size(&self) -> usize {
+        match self {
+            SaveVariant::Full => SAVE_FILE_SIZE,
+            SaveVariant::Half => HALF_SAVE_FILE_SIZE,
+            SaveVariant::Trimmed => GAME_SAVE_B_OFFSET + GAME_SAVE_B_SIZE,
+            SaveVariant::TrimmedHalf => GAME_SAVE_A_OFFSET + GAME_SAVE_A_SIZE,
+        }
+    }
+
+    /// Size of the flash the save data comes from, trimmed files are padded up to it.
+    fn flash_size(&self) -> usize {
+        match self {
+            SaveVariant::Full | SaveVariant::Trimmed => SAVE_FILE_SIZE,
+            SaveVariant::Half | SaveVariant::TrimmedHalf => HALF_SAVE_FILE_SIZE,
+        }
+    }
+
+    /// Finds the variant of a file of `size` bytes, the remaining bytes being the trailer.
+    fn detect(size: usize) -> Result<SaveVariant, SaveDataError> {
+        [
+            SaveVariant::Full,
+            SaveVariant::Trimmed,
+            SaveVariant::Half,
+            SaveVariant::TrimmedHalf,
+        ]
+        .into_iter()
+        .find(|variant| size >= variant.size() && size - variant.size() < MAX_TRAILER_SIZE)
+        .ok_or(SaveDataError::InvalidFileSize(size))
+    }
+}
+
+/// Extra bytes appended by emulators after the save data.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Trailer {
+    None,
+    /// 16 bytes of real-time clock data, as written by mGBA.
+    Rtc,
+    /// Any other number of bytes, such as the ones written by VisualBoyAdvance.
+    Unknown(usize),
+}
+
+/// Wallpaper of a PC box.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Wallpaper {
+    Forest,
+    City,
+    Desert,
+    Savanna,
+    Crag,
+    Volcano,
+    Snow,
+    Cave,
+    Beach,
+    Seafloor,
+    River,
+    Sky,
+    PolkaDot,
+    PokeCenter,
+    Machine,
+    Plain,
+    /// Emerald only, shows the pattern picked with the wallpaper password given by a friend.
+    Friends,
+}
+
+impl TryFrom<u8> for Wallpaper {
+    type Error = SaveDataError;
+
+    fn try_from(wallpaper: u8) -> Result<Self, Self::Error> {
+        const WALLPAPERS: [Wallpaper; 17] = [
+            Wallpaper::Forest,
+            Wallpaper::City,
+            Wallpaper::Desert,
+            Wallpaper::Savanna,
+            Wallpaper::Crag,
+            Wallpaper::Volcano,
+            Wallpaper::Snow,
+            Wallpaper::Cave,
+            Wallpaper::Beach,
+            Wallpaper::Seafloor,
+            Wallpaper::River,
+            Wallpaper::Sky,
+            Wallpaper::PolkaDot,
+            Wallpaper::PokeCenter,
+            Wallpaper::Machine,
+            Wallpaper::Plain,
+            Wallpaper::Friends,
+        ];
+
+        WALLPAPERS
+            .get(wallpaper as usize)
+            .copied()
+            .ok_or(SaveDataError::ValueOutOfRange {
+                value: wallpaper as u32,
+                max: Wallpaper::Friends as u32,
+            })
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum Pocket {
     Items,
     Pokeballs,
     Berries,
     Tms,
     Key,
+    /// Item storage of the player's PC, not part of the bag.
+    PcItems,
+}
+
+/// Generation III game a save file belongs to.
+///
+/// Ruby and Sapphire share the same layout, and so do FireRed and LeafGreen.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GameVersion {
+    RubySapphire,
+    Emerald,
+    FireRedLeafGreen,
 }
 
-fn pocket_address(pocket: Pocket, game_code: u32) -> (usize, usize) {
-    // For Ruby and Sapphire, this value will be 0x00000000.
-    // For FireRed and LeafGreen, this value will be 0x00000001.
-    // For Emerald any value other than 0 or 1 can be used.
-    // Determine offsets dynamically based on game version
-    match pocket {
-        Pocket::Items => match game_code {
-            0x00000000 => (0x0560, 0x05B0), // Ruby/Sapphire
-            0x00000001 => (0x0310, 0x03B8), // FireRed/LeafGreen
-            _ => (0x0560, 0x05D8),          // Emerald
-        },
-        Pocket::Pokeballs => match game_code {
-            0x00000000 => (0x0600, 0x0640), // Ruby/Sapphire
-            0x00000001 => (0x0430, 0x0464), // FireRed/LeafGreen
-            _ => (0x0650, 0x0690),          // Emerald
-        },
-        Pocket::Berries => match game_code {
-            0x00000000 => (0x0740, 0x7F8), // Ruby/Sapphire
-            0x00000001 => (0x054C, 0x5F8), // FireRed/LeafGreen
-            _ => (0x0790, 0x848),          // Emerald
-        },
-        Pocket::Tms => match game_code {
-            0x00000000 => (0x0640, 0x0740), // Ruby/Sapphire
-            0x00000001 => (0x0464, 0x054C), // FireRed/LeafGreen
-            _ => (0x0690, 0x0790),          // Emerald
-        },
-        Pocket::Key => match game_code {
-            0x00000000 => (0x05B0, 0x0600), // Ruby/Sapphire
-            0x00000001 => (0x03B8, 0x0430), // FireRed/LeafGreen
-            _ => (0x05D8, 0x0650),          // Emerald
-        },
+impl GameVersion {
+    /// Detects the game version from the Trainer Info section data.
+    ///
+    /// The game code at 0x00AC is 0 for Ruby/Sapphire, 1 for FireRed/LeafGreen and the security
+    /// key for Emerald, but Ruby/Sapphire keep Battle Tower data at that offset, so it can hold
+    /// any value. Ruby/Sapphire only use the first 0x890 bytes of the section, while the other
+    /// games always store data past them. FireRed/LeafGreen keep their security key at 0x0F20,
+    /// also masking the Berry Powder amount at 0x0AF8.
+    fn detect(trainer_info: &[u8]) -> GameVersion {
+        let game_code = LittleEndian::read_u32(&trainer_info[0x00AC..0x00AC + 4]);
+        let frlg_key = LittleEndian::read_u32(&trainer_info[0x0F20..0x0F20 + 4]);
+        let berry_powder = LittleEndian::read_u32(&trainer_info[0x0AF8..0x0AF8 + 4]) ^ frlg_key;
+
+        let frlg_key_found = frlg_key != 0 && berry_powder <= MAX_BERRY_POWDER;
+        let extended_trainer_info = trainer_info[RS_TRAINER_INFO_SIZE..TRAINER_INFO_SECTION_SIZE]
+            .iter()
+            .any(|byte| *byte != 0);
+
+        match game_code {
+            0x00000000 => GameVersion::RubySapphire,
+            0x00000001 if frlg_key_found || extended_trainer_info => GameVersion::FireRedLeafGreen,
+            _ if extended_trainer_info => GameVersion::Emerald,
+            _ => GameVersion::RubySapphire,
+        }
+    }
+
+    /// Fields of the game save of this game version.
+    pub fn layout(&self) -> &'static Layout {
+        match self {
+            GameVersion::RubySapphire => &layout::RUBY_SAPPHIRE,
+            GameVersion::Emerald => &layout::EMERALD,
+            GameVersion::FireRedLeafGreen => &layout::FIRE_RED_LEAF_GREEN,
+        }
     }
 }
 
 impl SaveFile {
+    /// Reads a save file without reporting problems, an invalid file gives an empty save file.
+    #[deprecated(
+        since = "0.2.0",
+        note = "use `SaveFile::parse`, which reports invalid files"
+    )]
     pub fn new(data: &[u8]) -> Self {
-        let mut game_save_a: [Section; NUMBER_GAME_SAVE_SECTIONS] =
-            [Section::default(); NUMBER_GAME_SAVE_SECTIONS];
-        let mut game_save_b: [Section; NUMBER_GAME_SAVE_SECTIONS] =
-            [Section::default(); NUMBER_GAME_SAVE_SECTIONS];
-
-        // Read each game save block, the two sets of 14 game save sections
-        for i in 0..NUMBER_GAME_SAVE_SECTIONS {
-            let save_a_offset = GAME_SAVE_A_OFFSET + (i * SECTION_SIZE);
-            let save_b_offset = GAME_SAVE_B_OFFSET + (i * SECTION_SIZE);
-
-            game_save_a[i] = Section {
-                offset: save_a_offset,
-                size: SECTION_SIZE,
-            };
-            game_save_b[i] = Section {
-                offset: save_b_offset,
-                size: SECTION_SIZE,
-            };
-        }
+        SaveFile::parse(data).unwrap_or_default()
+    }
+
+    /// Parses a save file, validating its structure.
+    ///
+    /// The file must be one of the variants described in the module documentation, optionally
+    /// followed by a trailer, and at least one game save block must have the section signature
+    /// on every section, each of the 14 sections exactly once, and the same save index on all of
+    /// them.
+    pub fn parse(data: &[u8]) -> Result<Self, SaveDataError> {
+        let variant = SaveVariant::detect(data.len())?;
+        let (save_data, trailer) = data.split_at(variant.size());
+
+        // Trimmed files leave out the end of the flash, which reads as erased
+        let mut flash = save_data.to_vec();
+        flash.resize(variant.flash_size(), 0xFF);
+
+        let game_save_b = match variant.flash_size() {
+            SAVE_FILE_SIZE => Some(game_save_sections(GAME_SAVE_B_OFFSET)),
+            _ => None,
+        };
 
         let mut save = SaveFile {
-            game_save_a,
+            game_save_a: game_save_sections(GAME_SAVE_A_OFFSET),
             game_save_b,
-            data: data.to_vec(),
+            data: flash,
             pc_buffer: PCBuffer::default(),
+            selected: None,
+            selection: None,
+            variant,
+            trailer: trailer.to_vec(),
         };
 
-        save.init_pc_buffer();
+        // A blank or corrupted block is fine as long as the other one can be used
+        let validation_a = save.validate_block(&save.game_save_a);
+        let validation_b = match &save.game_save_b {
+            Some(game_save_b) => save.validate_block(game_save_b),
+            None => Err(SaveDataError::InvalidFileSize(HALF_SAVE_FILE_SIZE)),
+        };
+
+        if let (Err(err), Err(_)) = (validation_a, validation_b) {
+            return Err(err);
+        }
 
-        save
+        save.refresh_selection();
+        save.init_pc_buffer()?;
+
+        Ok(save)
     }
 
     pub fn is_empty(&self) -> bool {
         self.data.len() == 0
     }
 
-    pub fn ot_name(&self) -> Vec<u8> {
-        let section = self
-            .get_section(SectionID::TrainerInfo)
-            .expect("Expected value but found None");
-        let section_data_buffer = section.data(&self.data);
+    pub fn ot_name(&self) -> Result<Vec<u8>, SaveDataError> {
+        self.field(self.layout()?.player_name)
+    }
+
+    pub fn ot_id(&self) -> Result<TrainerID, SaveDataError> {
+        let mut id = [0; 4];
+        id.copy_from_slice(&self.field(self.layout()?.trainer_id)?);
+
+        Ok(id.into())
+    }
+
+    /// Reads the player profile.
+    pub fn trainer(&self) -> Result<Trainer, SaveDataError> {
+        let layout = self.layout()?;
+        let security_key = self.security_key()?;
+
+        Ok(Trainer {
+            name: trainer::decode_name(&self.field(layout.player_name)?),
+            gender: self.field(layout.player_gender)?[0].into(),
+            id: self.ot_id()?,
+            play_time: PlayTime::new(&self.field(layout.play_time)?),
+            money: LittleEndian::read_u32(&self.field(layout.money)?) ^ security_key,
+            coins: LittleEndian::read_u16(&self.field(layout.coins)?) ^ security_key as u16,
+        })
+    }
+
+    /// Up to 7 characters of the game character set.
+    pub fn set_trainer_name(&mut self, name: &str) -> Result<(), SaveDataError> {
+        let field = self.layout()?.player_name;
+        self.set_field(field, &trainer::encode_name(name, field.size)?)
+    }
+
+    pub fn set_trainer_gender(&mut self, gender: TrainerGender) -> Result<(), SaveDataError> {
+        let field = self.layout()?.player_gender;
+        self.set_field(field, &[gender.into()])
+    }
+
+    pub fn set_trainer_id(&mut self, id: TrainerID) -> Result<(), SaveDataError> {
+        let field = self.layout()?.trainer_id;
+        self.set_field(field, &<[u8; 4]>::from(id))
+    }
+
+    pub fn set_play_time(&mut self, play_time: PlayTime) -> Result<(), SaveDataError> {
+        let field = self.layout()?.play_time;
+        self.set_field(field, &play_time.raw_data()?)
+    }
+
+    /// Up to 999999, stored XORed with the security key.
+    pub fn set_money(&mut self, money: u32) -> Result<(), SaveDataError> {
+        out_of_range(money, MAX_MONEY)?;
+
+        let field = self.layout()?.money;
+        let money = money ^ self.security_key()?;
+        self.set_field(field, &money.to_le_bytes())
+    }
+
+    /// Up to 9999, stored XORed with the lower 16 bits of the security key.
+    pub fn set_coins(&mut self, coins: u16) -> Result<(), SaveDataError> {
+        out_of_range(coins as u32, MAX_COINS as u32)?;
+
+        let field = self.layout()?.coins;
+        let coins = coins ^ self.security_key_lower()?;
+        self.set_field(field, &coins.to_le_bytes())
+    }
+
+    /// Reads the event flag array, see the `flags` module.
+    pub fn flags(&self) -> Result<Flags, SaveDataError> {
+        Ok(Flags::new(self.field(self.layout()?.flags)?))
+    }
+
+    /// Writes the event flag array back into the save file.
+    pub fn save_flags(&mut self, flags: &Flags) -> Result<(), SaveDataError> {
+        let field = self.layout()?.flags;
+        self.set_field(field, flags.raw_data())
+    }
+
+    pub fn flag(&self, flag: u16) -> Result<bool, SaveDataError> {
+        self.flags()?.get(flag)
+    }
+
+    pub fn set_flag(&mut self, flag: u16, value: bool) -> Result<(), SaveDataError> {
+        let mut flags = self.flags()?;
+        flags.set(flag, value)?;
+        self.save_flags(&flags)
+    }
+
+    /// Reads the game variable array, see the `vars` module.
+    pub fn vars(&self) -> Result<Vars, SaveDataError> {
+        Ok(Vars::new(self.field(self.layout()?.vars)?))
+    }
+
+    /// Writes the game variable array back into the save file.
+    pub fn save_vars(&mut self, vars: &Vars) -> Result<(), SaveDataError> {
+        let field = self.layout()?.vars;
+        self.set_field(field, vars.raw_data())
+    }
+
+    pub fn var(&self, var: impl Into<u16>) -> Result<u16, SaveDataError> {
+        self.vars()?.get(var)
+    }
+
+    pub fn set_var(&mut self, var: impl Into<u16>, value: u16) -> Result<(), SaveDataError> {
+        let mut vars = self.vars()?;
+        vars.set(var, value)?;
+        self.save_vars(&vars)
+    }
+
+    /// Reads the Pokédex flags and personality values from Trainer Info, see the `pokedex` module.
+    pub fn pokedex(&self) -> Result<Pokedex, SaveDataError> {
+        let layout = self.layout()?;
+
+        Ok(Pokedex::new(
+            self.field(layout.pokedex_owned)?,
+            self.field(layout.pokedex_seen)?,
+            LittleEndian::read_u32(&self.field(layout.pokedex_unown_personality)?),
+            LittleEndian::read_u32(&self.field(layout.pokedex_spinda_personality)?),
+        ))
+    }
+
+    /// Writes the Pokédex flags and personality values back into the save file, along with both
+    /// copies of the seen flags.
+    pub fn save_pokedex(&mut self, pokedex: &Pokedex) -> Result<(), SaveDataError> {
+        let layout = self.layout()?;
+
+        self.set_field(layout.pokedex_owned, pokedex.owned_data())?;
+        self.set_field(layout.pokedex_seen, pokedex.seen_data())?;
+        self.set_field(
+            layout.pokedex_unown_personality,
+            &pokedex.unown_personality_value().to_le_bytes(),
+        )?;
+        self.set_field(
+            layout.pokedex_spinda_personality,
+            &pokedex.spinda_personality_value().to_le_bytes(),
+        )?;
+        for field in layout.pokedex_seen_copies {
+            self.set_field(field, pokedex.seen_data())?;
+        }
+
+        Ok(())
+    }
+
+    /// Whether both copies of the seen flags agree with the ones in Trainer Info.
+    pub fn is_pokedex_consistent(&self) -> Result<bool, SaveDataError> {
+        let layout = self.layout()?;
+        let seen = self.field(layout.pokedex_seen)?;
+
+        for field in layout.pokedex_seen_copies {
+            if self.field(field)? != seen {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    pub fn is_national_dex_unlocked(&self) -> Result<bool, SaveDataError> {
+        let version = self.game_version()?;
+        let magic = self.field(version.layout().national_dex_magic)?[0];
+
+        Ok(magic == pokedex::national_dex_unlock(version).magic)
+    }
+
+    /// Unlocks the National Pokédex the same way the game does, writing the magic value, the
+    /// event flag and the game variable.
+    pub fn unlock_national_dex(&mut self) -> Result<(), SaveDataError> {
+        let version = self.game_version()?;
+        let unlock = pokedex::national_dex_unlock(version);
+
+        self.set_field(version.layout().national_dex_magic, &[unlock.magic])?;
+        self.set_flag(unlock.flag, true)?;
+        self.set_var(unlock.var, unlock.var_value)
+    }
+
+    /// Party members, as many as `SaveFile::party_len`. Slots past the last member are left out.
+    pub fn get_party(&self) -> Result<Vec<Pokemon>, SaveDataError> {
+        let party = self.layout()?.party;
+        let party_start = self.get_section(party.section)?.offset() + party.offset;
+        let len = self.party_len()?;
+
+        let mut team: Vec<Pokemon> = vec![];
+
+        for (i, pokemon_data) in self.field(party)?.chunks(100).take(len).enumerate() {
+            let offset = party_start + (i * 100);
+            let mut pokemon = Pokemon::new(offset, pokemon_data);
+            pokemon.set_slot(SlotRef::Party(i));
+            team.push(pokemon);
+        }
+
+        Ok(team)
+    }
+
+    /// Number of Pokémon in the party, as counted by the game.
+    pub fn party_len(&self) -> Result<usize, SaveDataError> {
+        let party_count = self.field(self.layout()?.party_count)?;
+        Ok((LittleEndian::read_u32(&party_count) as usize).min(PARTY_SIZE))
+    }
+
+    /// Adds a Pokémon after the last party member and returns its slot.
+    ///
+    /// The status, level, HP and stats the game keeps for party members are computed from the
+    /// Pokémon data, so a Pokémon read from a PC box can be added as is.
+    pub fn add_to_party(&mut self, pokemon: Pokemon) -> Result<usize, SaveDataError> {
+        let len = self.party_len()?;
+
+        if len == PARTY_SIZE {
+            return Err(SaveDataError::PartyFull);
+        }
+
+        let mut party = self.field(self.layout()?.party)?;
+        party[len * PARTY_POKEMON_SIZE..(len + 1) * PARTY_POKEMON_SIZE]
+            .copy_from_slice(&pokemon.raw_data());
+        self.save_party(&party, len + 1)?;
+
+        Ok(len)
+    }
+
+    /// Removes a party member, moving up the ones after it, and returns it.
+    ///
+    /// The returned Pokémon is no longer tied to a slot, its `Pokemon::slot` is `None`. The game
+    /// expects at least one Pokémon in the party, removing the last one is left to the caller.
+    pub fn remove_from_party(&mut self, index: usize) -> Result<Pokemon, SaveDataError> {
+        let len = self.party_len()?;
+
+        if index >= len {
+            return Err(SaveDataError::InvalidPartySlot(index));
+        }
+
+        let mut party = self.field(self.layout()?.party)?;
+        let pokemon = Pokemon::new(
+            0,
+            &party[index * PARTY_POKEMON_SIZE..(index + 1) * PARTY_POKEMON_SIZE],
+        );
+
+        party.copy_within(
+            (index + 1) * PARTY_POKEMON_SIZE..len * PARTY_POKEMON_SIZE,
+            index * PARTY_POKEMON_SIZE,
+        );
+        party[(len - 1) * PARTY_POKEMON_SIZE..len * PARTY_POKEMON_SIZE].fill(0);
+        self.save_party(&party, len - 1)?;
+
+        Ok(pokemon)
+    }
+
+    /// Swaps two party members.
+    pub fn swap_party(&mut self, i: usize, j: usize) -> Result<(), SaveDataError> {
+        let len = self.party_len()?;
+
+        for index in [i, j] {
+            if index >= len {
+                return Err(SaveDataError::InvalidPartySlot(index));
+            }
+        }
+
+        let mut party = self.field(self.layout()?.party)?;
+        let (first, second) = (i.min(j), i.max(j));
+
+        if first != second {
+            let (left, right) = party.split_at_mut(second * PARTY_POKEMON_SIZE);
+            left[first * PARTY_POKEMON_SIZE..(first + 1) * PARTY_POKEMON_SIZE]
+                .swap_with_slice(&mut right[..PARTY_POKEMON_SIZE]);
+        }
+
+        self.save_party(&party, len)
+    }
+
+    /// Writes the party slots along with the number of members.
+    fn save_party(&mut self, party: &[u8], len: usize) -> Result<(), SaveDataError> {
+        let layout = self.layout()?;
+
+        self.set_field(layout.party_count, &(len as u32).to_le_bytes())?;
+        self.set_field(layout.party, party)
+    }
+
+    /// Moves a Pokémon to another party, PC box or Day Care slot, swapping it with the Pokémon
+    /// already there if any.
+    ///
+    /// Pokémon moved into the party get the status, level, HP and stats the game keeps for party
+    /// members. Moving to a party slot past the last member adds the Pokémon at the end of the
+    /// party, and moving a party member to an empty box slot moves up the members after it. The
+    /// last party member can only be swapped with a boxed Pokémon.
+    ///
+    /// The mail and step count of the Day Care slots are left as they are.
+    pub fn move_pokemon(&mut self, from: SlotRef, to: SlotRef) -> Result<(), SaveDataError> {
+        let source = self.slot_data(from)?;
+
+        if Pokemon::new(0, &source).is_empty() {
+            return Err(SaveDataError::EmptySlot(from));
+        }
+
+        match (from, to) {
+            (SlotRef::Party(i), SlotRef::Party(j)) => self.swap_party(i, j),
+            (_, SlotRef::Party(j)) => {
+                let pokemon = Pokemon::new(0, &source);
+
+                if j >= PARTY_SIZE {
+                    return Err(SaveDataError::InvalidPartySlot(j));
+                }
+
+                if j < self.party_len()? {
+                    let target = self.slot_data(to)?;
+                    self.write_party_slot(j, &pokemon.raw_data())?;
+                    self.write_stored_slot(from, &target[..PC_POKEMON_SIZE])
+                } else {
+                    self.add_to_party(pokemon)?;
+                    self.write_stored_slot(from, &[0; PC_POKEMON_SIZE])
+                }
+            }
+            (SlotRef::Party(i), _) => {
+                let target = self.slot_data(to)?;
+
+                if Pokemon::new(0, &target).is_empty() {
+                    // The game never leaves the party empty
+                    if self.party_len()? == 1 {
+                        return Err(SaveDataError::LastPartyMember);
+                    }
+
+                    self.remove_from_party(i)?;
+                } else {
+                    self.write_party_slot(i, &Pokemon::new(0, &target).raw_data())?;
+                }
+
+                self.write_stored_slot(to, &source[..PC_POKEMON_SIZE])
+            }
+            _ => {
+                let target = self.slot_data(to)?;
+                self.write_stored_slot(to, &source)?;
+                self.write_stored_slot(from, &target)
+            }
+        }
+    }
+
+    /// Raw data of a party member or of a PC box slot.
+    fn slot_data(&self, slot: SlotRef) -> Result<Vec<u8>, SaveDataError> {
+        match slot {
+            SlotRef::Party(i) => {
+                if i >= self.party_len()? {
+                    return Err(SaveDataError::InvalidPartySlot(i));
+                }
+
+                let party = self.field(self.layout()?.party)?;
+                Ok(party[i * PARTY_POKEMON_SIZE..(i + 1) * PARTY_POKEMON_SIZE].to_vec())
+            }
+            SlotRef::Box(number, i) => Ok(self.pc_buffer.slot_data(number, i)?.to_vec()),
+            SlotRef::Daycare(i) => self.field(self.daycare_field(i)?),
+        }
+    }
+
+    fn write_party_slot(&mut self, i: usize, data: &[u8]) -> Result<(), SaveDataError> {
+        let field = self.layout()?.party;
+        let mut party = self.field(field)?;
+
+        party[i * PARTY_POKEMON_SIZE..(i + 1) * PARTY_POKEMON_SIZE].copy_from_slice(data);
+        self.set_field(field, &party)
+    }
+
+    /// Writes a PC box or Day Care slot, both holding Pokémon in the PC format.
+    fn write_stored_slot(&mut self, slot: SlotRef, data: &[u8]) -> Result<(), SaveDataError> {
+        match slot {
+            SlotRef::Box(number, i) => {
+                let offset = PCBuffer::slot_offset(number, i)?;
+                self.pc_buffer.write(offset, data, &mut self.data)
+            }
+            SlotRef::Daycare(i) => self.set_field(self.daycare_field(i)?, data),
+            SlotRef::Party(i) => Err(SaveDataError::InvalidPartySlot(i)),
+        }
+    }
+
+    fn daycare_field(&self, i: usize) -> Result<Field, SaveDataError> {
+        self.layout()?
+            .daycare
+            .get(i)
+            .copied()
+            .ok_or(SaveDataError::InvalidDaycareSlot(i))
+    }
+
+    /// Pokémon left at the Day Care, empty slots included.
+    ///
+    /// Only the Pokémon are read, the mail they hold and the steps walked since they were left
+    /// are not.
+    pub fn daycare(&self) -> Result<[Pokemon; DAYCARE_SLOTS], SaveDataError> {
+        let mut daycare = [Pokemon::default(); DAYCARE_SLOTS];
+
+        for (i, pokemon) in daycare.iter_mut().enumerate() {
+            let field = self.daycare_field(i)?;
+            let offset = self.get_section(field.section)?.offset() + field.offset;
+
+            *pokemon = Pokemon::new(offset, &self.field(field)?);
+            pokemon.set_slot(SlotRef::Daycare(i));
+        }
+
+        Ok(daycare)
+    }
+
+    /// Number of PC boxes.
+    pub fn box_count(&self) -> usize {
+        PC_BOX_COUNT
+    }
+
+    /// Number of slots of each PC box.
+    pub fn slots_per_box(&self) -> usize {
+        PC_BOX_SLOTS
+    }
+
+    /// Pokémon of a PC box, from 0 to `box_count() - 1`. Empty slots are included.
+    pub fn pc_box(&self, number: usize) -> Result<[Pokemon; PC_BOX_SLOTS], SaveDataError> {
+        self.pc_buffer.pc_box(number)
+    }
+
+    /// Every Pokémon stored in the PC boxes along with its slot, empty slots are skipped.
+    pub fn pc_pokemon(&self) -> impl Iterator<Item = (SlotRef, Pokemon)> + '_ {
+        (0..PC_BOX_COUNT).flat_map(move |number| {
+            self.pc_box(number)
+                .into_iter()
+                .flatten()
+                .enumerate()
+                .filter(|(_, pokemon)| !pokemon.is_empty())
+                .map(move |(i, pokemon)| (SlotRef::Box(number, i), pokemon))
+        })
+    }
+
+    pub fn is_pc_empty(&self) -> bool {
+        self.pc_buffer.is_empty()
+    }
+
+    /// PC box shown when opening the PC.
+    ///
+    /// The game keeps it in a single byte followed by padding, values past the last box are
+    /// refused.
+    pub fn current_box(&self) -> Result<usize, SaveDataError> {
+        let current_box = self.pc_buffer.read(0x0000, 1)?[0];
+        PCBuffer::box_number(current_box as usize)
+    }
+
+    pub fn set_current_box(&mut self, number: usize) -> Result<(), SaveDataError> {
+        PCBuffer::box_number(number)?;
+        self.pc_buffer
+            .write(0x0000, &[number as u8], &mut self.data)
+    }
+
+    pub fn box_name(&self, number: usize) -> Result<String, SaveDataError> {
+        let offset = PC_BOX_NAMES_OFFSET + PCBuffer::box_number(number)? * PC_BOX_NAME_SIZE;
+        Ok(trainer::decode_name(
+            self.pc_buffer.read(offset, PC_BOX_NAME_SIZE)?,
+        ))
+    }
+
+    /// Box names hold up to 8 characters.
+    pub fn set_box_name(&mut self, number: usize, name: &str) -> Result<(), SaveDataError> {
+        let offset = PC_BOX_NAMES_OFFSET + PCBuffer::box_number(number)? * PC_BOX_NAME_SIZE;
+        let mut name = trainer::encode_name(name, PC_BOX_NAME_SIZE - 1)?;
+        name.push(0xFF);
+
+        self.pc_buffer.write(offset, &name, &mut self.data)
+    }
+
+    pub fn box_wallpaper(&self, number: usize) -> Result<Wallpaper, SaveDataError> {
+        let offset = PC_BOX_WALLPAPERS_OFFSET + PCBuffer::box_number(number)?;
+        Wallpaper::try_from(self.pc_buffer.read(offset, 1)?[0])
+    }
+
+    /// The Friends wallpaper only exists in Emerald.
+    pub fn set_box_wallpaper(
+        &mut self,
+        number: usize,
+        wallpaper: Wallpaper,
+    ) -> Result<(), SaveDataError> {
+        let offset = PC_BOX_WALLPAPERS_OFFSET + PCBuffer::box_number(number)?;
+
+        if wallpaper == Wallpaper::Friends && self.game_version()? != GameVersion::Emerald {
+            return Err(SaveDataError::ValueOutOfRange {
+                value: wallpaper as u32,
+                max: Wallpaper::Plain as u32,
+            });
+        }
+
+        self.pc_buffer
+            .write(offset, &[wallpaper as u8], &mut self.data)
+    }
+
+    /// Writes a Pokémon into a party, PC box or Day Care slot, in the format of the slot.
+    ///
+    /// Party slots past the last member are refused, see `SaveFile::add_to_party`.
+    pub fn save_pokemon(&mut self, slot: SlotRef, pokemon: Pokemon) -> Result<(), SaveDataError> {
+        match slot {
+            SlotRef::Party(i) => {
+                if i >= self.party_len()? {
+                    return Err(SaveDataError::InvalidPartySlot(i));
+                }
+
+                self.write_party_slot(i, &pokemon.raw_data())
+            }
+            SlotRef::Box(..) | SlotRef::Daycare(_) => {
+                self.write_stored_slot(slot, &pokemon.raw_data()[..PC_POKEMON_SIZE])
+            }
+        }
+    }
+
+    /// For Ruby and Sapphire, this value will be 0x00000000.
+    /// For FireRed and LeafGreen, this value will be 0x00000001.
+    /// For Emerald any value other than 0 or 1 can be used.
+    ///
+    /// Ruby and Sapphire may hold any value here, use `SaveFile::game_version` to tell the games apart.
+    pub fn game_code(&self) -> Result<u32, SaveDataError> {
+        let game_code = self.field(self.layout()?.game_code)?;
+        Ok(LittleEndian::read_u32(&game_code))
+    }
+
+    /// Detects the game the save file belongs to.
+    pub fn game_version(&self) -> Result<GameVersion, SaveDataError> {
+        let section = self.get_section(SectionID::TrainerInfo)?;
+        Ok(GameVersion::detect(section.data(&self.data)))
+    }
+
+    fn layout(&self) -> Result<&'static Layout, SaveDataError> {
+        Ok(self.game_version()?.layout())
+    }
+
+    /// Reads a field of the current game save block.
+    ///
+    /// Fields of SaveBlock1 continue into the next section past the data size of their section,
+    /// see the `layout` module.
+    pub fn field(&self, field: Field) -> Result<Vec<u8>, SaveDataError> {
+        let mut data = Vec::with_capacity(field.size);
+
+        for (section, range) in self.field_sections(field)? {
+            data.extend_from_slice(&section.data(&self.data)[range]);
+        }
+
+        Ok(data)
+    }
+
+    /// Writes a field of the current game save block, updating the checksum of every section it
+    /// covers. Fields of the PC buffer sections are seen by the PC box accessors right away.
+    pub fn set_field(&mut self, field: Field, data: &[u8]) -> Result<(), SaveDataError> {
+        if data.len() != field.size {
+            return Err(SaveDataError::InvalidDataLength {
+                expected: field.size,
+                found: data.len(),
+            });
+        }
+
+        let mut written = 0;
+        for (section, range) in self.field_sections(field)? {
+            let length = range.len();
+            section.data_mut(&mut self.data)[range]
+                .copy_from_slice(&data[written..written + length]);
+            section.write_checksum(&mut self.data)?;
+            written += length;
+        }
+
+        // The PC boxes are edited through a copy of the PC buffer, refresh it
+        if (5..=13).contains(&i32::from(field.section)) {
+            self.init_pc_buffer()?;
+        }
+
+        Ok(())
+    }
+
+    /// Splits a field into the sections holding it and the range of the data of each section.
+    fn field_sections(&self, field: Field) -> Result<Vec<(Section, Range<usize>)>, SaveDataError> {
+        let mut sections = vec![];
+        let mut id = field.section;
+        let mut offset = field.offset;
+        let mut remaining = field.size;
+
+        loop {
+            let data_size = id.data_size();
+            if offset >= data_size {
+                return Err(SaveDataError::InvalidOffset(field.offset));
+            }
+
+            let length = remaining.min(data_size - offset);
+            sections.push((self.get_section(id)?, offset..offset + length));
+            remaining -= length;
+
+            if remaining == 0 {
+                return Ok(sections);
+            }
+
+            // Only SaveBlock1 is split across consecutive sections
+            id = match id {
+                SectionID::TeamItems => SectionID::GameState,
+                SectionID::GameState => SectionID::MiscData,
+                SectionID::MiscData => SectionID::RivalInfo,
+                _ => return Err(SaveDataError::InvalidOffset(field.offset + field.size)),
+            };
+            offset = 0;
+        }
+    }
+
+    /// The security_key location may vary depending on the game.
+    /// --------------------------------------
+    /// | Offset | Size | Game |
+    /// |   N/A  |  N/A |  RS  |
+    /// | 0x00AC |   4  |   E  |
+    /// | 0x0F20 |   4  | FrLg |
+    /// --------------------------------------
+    /// Ruby and Sapphire either do not utilize this masking operation, or the mask is always zero.
+    fn security_key(&self) -> Result<u32, SaveDataError> {
+        match self.layout()?.security_key {
+            Some(field) => Ok(LittleEndian::read_u32(&self.field(field)?)),
+            None => Ok(0x00000000),
+        }
+    }
+
+    fn security_key_lower(&self) -> Result<u16, SaveDataError> {
+        Ok(LittleEndian::read_u16(
+            &self.security_key()?.to_le_bytes()[..2],
+        ))
+    }
+
+    /// Retrieves the pocket data from the save file, one item per slot.
+    ///
+    /// Offsets and data encryption vary depending on the game version.
+    pub fn pocket(&self, pocket: Pocket) -> Result<Vec<Item>, SaveDataError> {
+        let field = self.layout()?.pocket(pocket);
+        let security_key = self.pocket_security_key(pocket)?;
+
+        Ok(SaveFile::decrypt_pocket(&self.field(field)?, security_key))
+    }
+
+    /// Saves the updated pocket data back into the save file.
+    ///
+    /// This function writes the modified pocket data into the corresponding save section,
+    /// encrypting it with the security key. The slots past the given items are emptied.
+    ///
+    /// Pockets failing `SaveFile::validate_pocket` are refused, see
+    /// `SaveFile::save_pocket_fixed` to fix them instead.
+    pub fn save_pocket(&mut self, pocket: Pocket, items: &[Item]) -> Result<(), SaveDataError> {
+        let violations = self.validate_pocket(pocket, items)?;
+
+        if !violations.is_empty() {
+            return Err(SaveDataError::InvalidPocket { pocket, violations });
+        }
+
+        self.write_pocket(pocket, items)
+    }
+
+    /// Same as `SaveFile::save_pocket`, fixing the items first: duplicates are merged and items
+    /// belonging in another bag pocket are added to that pocket.
+    ///
+    /// Nothing is written when a pocket still fails validation, such as one left with more
+    /// items than slots.
+    pub fn save_pocket_fixed(
+        &mut self,
+        pocket: Pocket,
+        items: &[Item],
+    ) -> Result<(), SaveDataError> {
+        let mut pockets: Vec<(Pocket, Vec<Item>)> = vec![(pocket, vec![])];
+
+        for item in items.iter().filter(|item| !item.is_empty()) {
+            let target = match item::item_pocket(item.id) {
+                Some(item_pocket) if pocket != Pocket::PcItems => item_pocket,
+                _ => pocket,
+            };
+
+            match pockets.iter_mut().find(|(other, _)| *other == target) {
+                Some((_, target_items)) => target_items.push(*item),
+                None => {
+                    let mut target_items = self.pocket(target)?;
+                    target_items.push(*item);
+                    pockets.push((target, target_items));
+                }
+            }
+        }
+
+        let version = self.game_version()?;
+        for (pocket, items) in pockets.iter_mut() {
+            *items = item::merge(items, item::max_quantity(version, *pocket));
+
+            let violations = self.validate_pocket(*pocket, items)?;
+            if !violations.is_empty() {
+                return Err(SaveDataError::InvalidPocket {
+                    pocket: *pocket,
+                    violations,
+                });
+            }
+        }
+
+        for (pocket, items) in pockets {
+            self.write_pocket(pocket, &items)?;
+        }
+
+        Ok(())
+    }
+
+    /// Lists what the game would not accept in the items of a pocket: items of another pocket,
+    /// duplicates, quantities above the limit and more items than slots.
+    ///
+    /// Several slots of the same item only count as duplicates when an earlier one still has
+    /// room, so a full stack followed by the rest of the item passes.
+    pub fn validate_pocket(
+        &self,
+        pocket: Pocket,
+        items: &[Item],
+    ) -> Result<Vec<PocketViolation>, SaveDataError> {
+        let slots = self.layout()?.pocket(pocket).size / ITEM_SLOT_SIZE;
+        let max_quantity = item::max_quantity(self.game_version()?, pocket);
+
+        Ok(item::validate(pocket, items, slots, max_quantity))
+    }
+
+    /// Sorts the items of a pocket, leaving the empty slots at the end.
+    ///
+    /// The items are only reordered, so the pocket is written back without validation.
+    pub fn sort_pocket(&mut self, pocket: Pocket, order: SortOrder) -> Result<(), SaveDataError> {
+        let mut items = self.pocket(pocket)?;
+        item::sort(&mut items, order, self.game_version()?, pocket);
+
+        self.write_pocket(pocket, &items)
+    }
+
+    /// Moves the empty slots of a pocket to the end, keeping the order of the items.
+    pub fn compact_pocket(&mut self, pocket: Pocket) -> Result<(), SaveDataError> {
+        let items: Vec<Item> = self
+            .pocket(pocket)?
+            .into_iter()
+            .filter(|item| !item.is_empty())
+            .collect();
+
+        self.write_pocket(pocket, &items)
+    }
+
+    fn write_pocket(&mut self, pocket: Pocket, items: &[Item]) -> Result<(), SaveDataError> {
+        let field = self.layout()?.pocket(pocket);
+        let slots = field.size / ITEM_SLOT_SIZE;
+
+        if items.len() > slots {
+            return Err(SaveDataError::InvalidDataLength {
+                expected: slots,
+                found: items.len(),
+            });
+        }
+
+        // The game masks the quantity of empty slots as well
+        let mut items = items.to_vec();
+        items.resize(slots, Item::default());
+
+        let security_key = self.pocket_security_key(pocket)?;
+        let encrypted_bag = SaveFile::encrypt_pocket(&items, security_key);
+        self.set_field(field, &encrypted_bag)
+    }
+
+    /// Key masking the quantities of a pocket. The PC item storage is never masked.
+    fn pocket_security_key(&self, pocket: Pocket) -> Result<u16, SaveDataError> {
+        match pocket {
+            Pocket::PcItems => Ok(0),
+            _ => self.security_key_lower(),
+        }
+    }
+
+    /// Helper function to decrypt pocket data using the security key.
+    ///
+    /// Each pocket entry consists of:
+    /// - First 2 bytes: Item ID (u16)
+    /// - Last 2 bytes: Quantity (u16, XORed with the security key)
+    fn decrypt_pocket(data: &[u8], security_key: u16) -> Vec<Item> {
+        data.chunks_exact(ITEM_SLOT_SIZE)
+            .map(|chunk| {
+                let item_id = LittleEndian::read_u16(&chunk[0..2]);
+                let encrypted_quantity = LittleEndian::read_u16(&chunk[2..4]);
+
+                Item::new(item_id, encrypted_quantity ^ security_key)
+            })
+            .collect()
+    }
+
+    /// Helper function to encrypt pocket data using the security key.
+    ///
+    /// # Arguments
+    /// - `pocket`: The items of the pocket slots.
+    /// - `security_key`: The security key used for encryption.
+    ///
+    /// # Returns
+    /// A byte vector containing the encrypted pocket data.
+    fn encrypt_pocket(pocket: &[Item], security_key: u16) -> Vec<u8> {
+        let mut encrypted_data = Vec::new();
+
+        for item in pocket {
+            let encrypted_quantity = item.quantity ^ security_key;
+
+            encrypted_data.extend(&item.id.to_le_bytes());
+            encrypted_data.extend(&encrypted_quantity.to_le_bytes());
+        }
+
+        encrypted_data
+    }
+
+    /// Reads the Hall of Fame teams, oldest first.
+    ///
+    /// Both sectors must carry the signature and a valid checksum. A Hall of Fame that was
+    /// never written, with no signature at all, holds no teams.
+    pub fn hall_of_fame(&self) -> Result<Vec<HallOfFameTeam>, SaveDataError> {
+        let offset = self.hall_of_fame_offset()?;
+        let sectors: Vec<&[u8]> = self.data[offset..offset + HALL_FAME_SIZE]
+            .chunks(SECTION_SIZE)
+            .collect();
+
+        let signed = |sector: &&[u8]| {
+            LittleEndian::read_u32(&sector[0x0FF8..0x0FFC]) == SIGNATURE_MAGIC_NUMBER
+        };
+        if !sectors.iter().any(signed) {
+            return Ok(vec![]);
+        }
+
+        let mut data = Vec::with_capacity(HALL_FAME_SIZE);
+
+        for (i, sector) in sectors.iter().enumerate() {
+            if !signed(sector) {
+                return Err(SaveDataError::InvalidSignature {
+                    offset: offset + i * SECTION_SIZE,
+                    found: LittleEndian::read_u32(&sector[0x0FF8..0x0FFC]),
+                });
+            }
+
+            let sector_data = &sector[..DEFAULT_SECTION_SIZE];
+            let expected = checksum(sector_data);
+            let found = LittleEndian::read_u16(&sector[0x0FF4..0x0FF6]);
+            if expected != found {
+                return Err(SaveDataError::ChecksumMismatch { expected, found });
+            }
+
+            data.extend_from_slice(sector_data);
+        }
 
-        section_data_buffer[0x0000..7].to_vec()
+        Ok(hall_of_fame::decode(&data))
     }
 
-    pub fn ot_id(&self) -> Vec<u8> {
-        let section = self
-            .get_section(SectionID::TrainerInfo)
-            .expect("Expected value but found None");
-        let section_data_buffer = section.data(&self.data);
+    /// Writes the Hall of Fame teams, oldest first, the same way the game does.
+    ///
+    /// Up to 50 teams of up to 6 Pokémon fit, teams without members are left out.
+    pub fn set_hall_of_fame(&mut self, teams: &[HallOfFameTeam]) -> Result<(), SaveDataError> {
+        let offset = self.hall_of_fame_offset()?;
+        let mut data = hall_of_fame::encode(teams)?;
+        data.resize(HALL_FAME_SIZE / SECTION_SIZE * DEFAULT_SECTION_SIZE, 0);
+
+        for (sector, sector_data) in self.data[offset..offset + HALL_FAME_SIZE]
+            .chunks_mut(SECTION_SIZE)
+            .zip(data.chunks(DEFAULT_SECTION_SIZE))
+        {
+            sector.fill(0);
+            sector[..DEFAULT_SECTION_SIZE].copy_from_slice(sector_data);
+            LittleEndian::write_u16(&mut sector[0x0FF4..0x0FF6], checksum(sector_data));
+            LittleEndian::write_u32(&mut sector[0x0FF8..0x0FFC], SIGNATURE_MAGIC_NUMBER);
+        }
 
-        section_data_buffer[0x000A..0x000A + 4].to_vec()
+        Ok(())
     }
 
-    pub fn get_party(&self) -> Result<Vec<Pokemon>, SaveDataError> {
-        let game_code = self.get_game_code()?;
-        let section = self
-            .get_section(SectionID::TeamItems)
-            .expect("Expected value but found None");
-        let section_data_buffer = section.data(&self.data);
-
-        let mut team: Vec<Pokemon> = vec![];
+    /// Removes every team from the Hall of Fame.
+    pub fn clear_hall_of_fame(&mut self) -> Result<(), SaveDataError> {
+        self.set_hall_of_fame(&[])
+    }
 
-        if game_code == 0x00000001 {
-            for (i, pokemon_data) in section_data_buffer[0x0038..0x0290].chunks(100).enumerate() {
-                let offset = section.offset() + 0x0038 + (i * 100);
-                let pokemon = Pokemon::new(offset, pokemon_data);
-                team.push(pokemon);
-            }
-        } else {
-            for (i, pokemon_data) in section_data_buffer[0x0238..0x0490].chunks(100).enumerate() {
-                let offset = section.offset() + 0x0238 + (i * 100);
-                let pokemon = Pokemon::new(offset, pokemon_data);
-                team.push(pokemon);
-            }
+    /// Only the full 128 KiB variant holds the Hall of Fame.
+    fn hall_of_fame_offset(&self) -> Result<usize, SaveDataError> {
+        if self.is_empty() {
+            return Err(SaveDataError::InvalidFileSize(0));
         }
 
-        Ok(team)
+        match self.variant {
+            SaveVariant::Full => Ok(HALL_FAME_OFFSET),
+            _ => Err(SaveDataError::HallOfFameNotFound),
+        }
     }
 
-    pub fn pc_box(&self, number: usize) -> Vec<Pokemon> {
-        self.pc_buffer.pc_box(number)
+    /// Bytes of the save file, in the variant it was parsed from and followed by its trailer.
+    pub fn raw_data(&self) -> Vec<u8> {
+        let size = self.variant.size().min(self.data.len());
+
+        let mut data = self.data[..size].to_vec();
+        data.extend_from_slice(&self.trailer);
+        data
     }
 
-    pub fn is_pc_empty(&self) -> bool {
-        self.pc_buffer.is_empty()
+    /// Variant of the parsed file.
+    pub fn variant(&self) -> SaveVariant {
+        self.variant
     }
 
-    pub fn save_pokemon(
-        &mut self,
-        storage: StorageType,
-        pokemon: Pokemon,
-    ) -> Result<(), SaveDataError> {
-        match storage {
-            StorageType::Party => {
-                let offset = pokemon.offset();
-
-                self.data[offset..offset + 100].copy_from_slice(&pokemon.raw_data());
-                let section = self
-                    .get_section(SectionID::TeamItems)
-                    .expect("Expected value but found None");
-                section.write_checksum(&mut self.data)?;
-            }
-            StorageType::PC => {
-                self.pc_buffer.save_pokemon(pokemon, &mut self.data)?;
-            }
-            _ => {}
+    /// Kind of trailer found after the save data.
+    pub fn trailer(&self) -> Trailer {
+        match self.trailer.len() {
+            0 => Trailer::None,
+            RTC_TRAILER_SIZE => Trailer::Rtc,
+            size => Trailer::Unknown(size),
         }
-        Ok(())
     }
 
-    /// For Ruby and Sapphire, this value will be 0x00000000.
-    /// For FireRed and LeafGreen, this value will be 0x00000001.
-    /// For Emerald any value other than 0 or 1 can be used.
-    pub fn game_code(&self) -> u32 {
-        let section = self
-            .get_section(SectionID::TrainerInfo)
-            .expect("Expected value but found None");
-        let section_data_buffer = section.data(&self.data);
-        LittleEndian::read_u32(&section_data_buffer[0x00AC..0x00AC + 4])
+    /// Bytes found after the save data.
+    pub fn trailer_data(&self) -> &[u8] {
+        &self.trailer
     }
 
-    /// The security_key location may vary depending on the game.
-    /// --------------------------------------
-    /// | Offset | Size | Game |
-    /// |   N/A  |  N/A |  RS  |
-    /// | 0x00AC |   4  |  RS  |
-    /// | 0x0AF8 |   4  | FrLg |
-    /// --------------------------------------
-    /// Ruby and Sapphire either do not utilize this masking operation, or the mask is always zero.
-    fn security_key(&self) -> u32 {
-        let game_code = self.game_code();
-
-        if game_code == 0x00000000 {
-            0x00000000
-        } else if game_code == 0x00000001 {
-            let section = self
-                .get_section(SectionID::TrainerInfo)
-                .expect("Expected value but found None");
-            let section_data_buffer = section.data(&self.data);
-            LittleEndian::read_u32(&section_data_buffer[0x0AF8..0x0AF8 + 4])
-        } else {
-            game_code
+    /// Writes the current game save block as a new save, the same way the game does when saving.
+    ///
+    /// The sections of the current block are copied into the older block with the save index
+    /// increased by one and the section order rotated once, and every checksum is recomputed. The
+    /// previous state stays in the file as the backup block.
+    pub fn commit(&mut self) -> Result<(), SaveDataError> {
+        if self.is_empty() {
+            return Err(SaveDataError::InvalidFileSize(0));
         }
-    }
 
-    fn security_key_lower(&self) -> u16 {
-        LittleEndian::read_u16(&self.security_key().to_le_bytes()[..2])
+        let current_save = self.current_save().to_vec();
+        let next_save = self.next_save().to_vec();
+
+        let save_index = current_save[0].save_index(&self.data).wrapping_add(1);
+
+        // The rotation is the position of the first section, Trainer Info, in the block
+        let rotation = current_save
+            .iter()
+            .position(|section| section.id(&self.data) == SectionID::TrainerInfo)
+            .ok_or(SaveDataError::SectionNotFound(SectionID::TrainerInfo))?;
+        let rotation = (rotation + 1) % NUMBER_GAME_SAVE_SECTIONS;
+
+        // Read every section before writing, 64 KiB saves are rewritten in place
+        let sections: Vec<(SectionID, Vec<u8>)> = current_save
+            .iter()
+            .map(|section| (section.id(&self.data), section.data(&self.data).to_vec()))
+            .collect();
+
+        for (id, data) in sections {
+            let index = i32::from(id) as usize;
+            let section = next_save[(index + rotation) % NUMBER_GAME_SAVE_SECTIONS];
+
+            // The game clears the bytes past the data size of the section
+            let section_data_buffer = section.data_mut(&mut self.data);
+            section_data_buffer.fill(0);
+            section_data_buffer[..id.data_size()].copy_from_slice(&data[..id.data_size()]);
+
+            section.write_footer(&mut self.data, id, save_index);
+            section.write_checksum(&mut self.data)?;
+        }
+
+        // The new save is now the most recent block
+        self.selected = None;
+        self.refresh_selection();
+
+        self.init_pc_buffer()
     }
 
-    /// Retrieves the pocket data from the save file.
+    /// Lists the game save blocks in the file with their save index and validity.
     ///
-    /// Offsets and data encryption vary depending on the game version.
-    pub fn pocket(&self, pocket: Pocket) -> Result<Vec<(String, u16)>, SaveDataError> {
-        let game_code = self.game_code();
-        let (start, end) = pocket_address(pocket, game_code);
-        self.read_pocket(start, end)
+    /// A block is valid when its structure is correct and every section checksum matches, which
+    /// is what the game requires to load it.
+    pub fn blocks(&self) -> Vec<BlockInfo> {
+        if self.is_empty() {
+            return vec![];
+        }
+
+        let current_block = self.current_block();
+
+        [SaveBlock::A, SaveBlock::B]
+            .into_iter()
+            .filter_map(|block| {
+                let sections = self.block_sections(block)?;
+
+                Some(BlockInfo {
+                    block,
+                    save_index: sections[0].save_index(&self.data),
+                    valid: self.block_status(sections) == BlockStatus::Valid,
+                    current: block == current_block,
+                })
+            })
+            .collect()
     }
 
-    /// Saves the updated pocket data back into the save file.
+    /// Opens a view of the save file onto the given game save block, e.g. the backup block.
     ///
-    /// This function writes the modified pocket data into the corresponding save section,
-    /// encrypting it with the security key.
-    pub fn save_pocket(&mut self, pocket_type: Pocket, pocket_list: Vec<(String, u16)>) -> Result<(), SaveDataError> {
-        let game_code = self.game_code();
-        let security_key = self.security_key_lower();
-        let (start, end) = pocket_address(pocket_type, game_code);
+    /// Every accessor of the returned save file reads from and writes to that block.
+    pub fn view_block(&self, block: SaveBlock) -> Result<SaveFile, SaveDataError> {
+        if self.is_empty() {
+            return Err(SaveDataError::InvalidFileSize(0));
+        }
 
-        let section = self
-            .get_section(SectionID::TeamItems)
-            .ok_or(SaveDataError::SectionNotFound(SectionID::TeamItems))?;
-        let section_data_buffer: &mut [u8] = section.data_mut(&mut self.data);
+        let sections = self
+            .block_sections(block)
+            .ok_or(SaveDataError::BlockNotFound(block))?;
+        self.validate_block(sections)?;
 
-        let encrypted_bag = SaveFile::encrypt_pocket(pocket_list, security_key)?;
-        section_data_buffer[start..end].copy_from_slice(&encrypted_bag);
-        section.write_checksum(&mut self.data)?;
+        let mut save = self.clone();
+        save.selected = Some(block);
+        save.init_pc_buffer()?;
 
-        Ok(())
+        Ok(save)
     }
 
-    fn read_pocket(&self, start: usize, end: usize) -> Result<Vec<(String, u16)>, SaveDataError> {
-        let security_key = self.security_key_lower();
+    /// Makes the backup block the most recent save.
+    ///
+    /// The save index of the backup block is set past the one of the current block and its
+    /// checksums are recomputed, so the game loads it while the current block is kept as the new
+    /// backup.
+    pub fn promote_backup(&mut self) -> Result<(), SaveDataError> {
+        if self.is_empty() {
+            return Err(SaveDataError::InvalidFileSize(0));
+        }
+
+        let current_block = self.current_block();
+        let backup_block = match current_block {
+            SaveBlock::A => SaveBlock::B,
+            SaveBlock::B => SaveBlock::A,
+        };
+
+        let backup_save = self
+            .block_sections(backup_block)
+            .ok_or(SaveDataError::BlockNotFound(backup_block))?
+            .to_vec();
+        self.validate_block(&backup_save)?;
 
-        let section = self
-            .get_section(SectionID::TeamItems)
-            .ok_or(SaveDataError::SectionNotFound(SectionID::TeamItems))?;
-        let section_data_buffer = section.data(&self.data);
+        let save_index = self.current_save()[0]
+            .save_index(&self.data)
+            .wrapping_add(1);
+
+        for section in backup_save {
+            let id = section.id(&self.data);
+            section.write_footer(&mut self.data, id, save_index);
+            section.write_checksum(&mut self.data)?;
+        }
 
-        let bag = SaveFile::decrypt_pocket(&section_data_buffer[start..end], security_key)?;
+        self.selected = None;
+        self.refresh_selection();
 
-        Ok(bag)
+        self.init_pc_buffer()
     }
 
-    /// Helper function to decrypt pocket data using the security key.
-    ///
-    /// Each pocket entry consists of:
-    /// - First 2 bytes: Item ID (u16)
-    /// - Last 2 bytes: Quantity (u16, XORed with the security key)
-    fn decrypt_pocket(data: &[u8], security_key: u16) -> Result<Vec<(String, u16)>, SaveDataError> {
-        let mut pocket = Vec::new();
-
-        for chunk in data.chunks(4) {
-            if chunk.len() < 4 {
-                return Err(SaveDataError::InvalidDataLength {
-                    expected: 4,
-                    found: chunk.len(),
-                });
-            }
+    /// Recomputes the checksum of every section of the current game save block.
+    pub fn update_checksums(&mut self) -> Result<(), SaveDataError> {
+        if self.is_empty() {
+            return Err(SaveDataError::InvalidFileSize(0));
+        }
 
-            let item_id = LittleEndian::read_u16(&chunk[0..2]);
-            let encrypted_quantity = LittleEndian::read_u16(&chunk[2..4]);
-            let quantity = encrypted_quantity ^ security_key;
+        let current_save = self.current_save().to_vec();
 
-            let item_name = find_item(item_id as usize).unwrap_or_else(|_| "Unknown".to_string());
-            pocket.push((item_name, quantity));
+        for section in current_save {
+            section.write_checksum(&mut self.data)?;
         }
 
-        Ok(pocket)
+        // A block left corrupted may be valid again
+        self.refresh_selection();
+
+        Ok(())
     }
 
-    /// Helper function to encrypt pocket data using the security key.
-    ///
-    /// # Arguments
-    /// - `pocket`: A vector of tuples containing the item name and quantity.
-    /// - `security_key`: The security key used for encryption.
+    /// Verifies the checksum of every section of both game save blocks.
     ///
-    /// # Returns
-    /// A byte vector containing the encrypted pocket data.
-    fn encrypt_pocket(
-        pocket: Vec<(String, u16)>,
-        security_key: u16,
-    ) -> Result<Vec<u8>, SaveDataError> {
-        let mut encrypted_data = Vec::new();
+    /// Each section is checked over its real data size, which depends on the section ID, the same
+    /// way the game does when loading. A block whose report is not valid will be treated by the
+    /// game as corrupted.
+    pub fn verify_checksums(&self) -> Vec<ChecksumReport> {
+        if self.is_empty() {
+            return vec![];
+        }
 
-        for (item_name, quantity) in pocket {
-            let item_id = item_id_g3(&item_name).unwrap_or(0);
-            let encrypted_quantity = quantity ^ security_key;
+        let mut reports = vec![self.block_checksums(SaveBlock::A, &self.game_save_a)];
 
-            encrypted_data.extend(&item_id.to_le_bytes());
-            encrypted_data.extend(&encrypted_quantity.to_le_bytes());
+        if let Some(game_save_b) = &self.game_save_b {
+            reports.push(self.block_checksums(SaveBlock::B, game_save_b));
         }
 
-        Ok(encrypted_data)
+        reports
     }
 
-    pub fn raw_data(&self) -> Vec<u8> {
-        self.data.to_vec()
+    fn block_checksums(&self, block: SaveBlock, sections: &[Section]) -> ChecksumReport {
+        let mut sections: Vec<SectionChecksum> = sections
+            .iter()
+            .map(|section| SectionChecksum {
+                id: section.id(&self.data),
+                result: section.verify_checksum(&self.data),
+            })
+            .collect();
+
+        sections.sort_by_key(|section| section.id);
+
+        ChecksumReport { block, sections }
     }
 
-    fn init_pc_buffer(&mut self) {
+    fn init_pc_buffer(&mut self) -> Result<(), SaveDataError> {
         let current_save = self.current_save();
 
         let range = 5..=13;
 
         // Read all the Sections that hold the pc buffer from PCBufferA to PCbufferI
-        let mut sections: Vec<&Section> = current_save
+        let mut sections: Vec<Section> = current_save
             .iter()
             .filter(|section| range.contains(&section.id(&self.data).into()))
+            .copied()
             .collect();
 
-        sections.sort_by(|a, b| {
-            a.id(&self.data)
-                .partial_cmp(&b.id(&self.data))
-                .expect("Expected value but found None")
-        });
-
-        let sections: Vec<Section> = sections.iter().map(|section| **section).collect();
+        sections.sort_by_key(|section| section.id(&self.data));
 
         // Store them into an array for easy access
-        let mut pc_buffer: [Section; 9] = [Section::default(); 9];
-
-        pc_buffer.copy_from_slice(sections.as_slice());
+        let pc_buffer: [Section; 9] = sections
+            .try_into()
+            .map_err(|_| SaveDataError::SectionNotFound(SectionID::PCbufferA))?;
 
         self.pc_buffer = PCBuffer::new(pc_buffer, &self.data);
+
+        Ok(())
     }
 
-    /// Determines the most recent save block (A or B) based on the save index.
+    /// Determines the save block (A or B) in use and why it was picked, following the game logic.
     ///
-    /// Each section in the save file contains a save index, but only the index in the last section is
-    /// considered when determining the most recent save. The save index increases every time the game
-    /// is saved, even when starting a new game.
+    /// A block is only considered valid when every section has the signature, a correct checksum
+    /// and the same save index, and all 14 sections are present. When both blocks are valid the
+    /// most recent one, with the greater save index, is picked. The save index increases every
+    /// time the game is saved, even when starting a new game, so it can wrap around from
+    /// 0xFFFFFFFF to 0.
     ///
-    /// Returns a slice of sections corresponding to the most recent save block.
-    fn current_save(&self) -> &[Section] {
+    /// When neither block is valid, the most recent block with a correct structure is picked so
+    /// its data can still be read and repaired.
+    ///
+    /// The block is picked when parsing the file and again by `SaveFile::commit`,
+    /// `SaveFile::promote_backup` and `SaveFile::update_checksums`, edits in between keep using
+    /// it.
+    pub fn block_selection(&self) -> BlockSelection {
+        if let Some(selected) = self.selected {
+            return BlockSelection::Selected(selected);
+        }
+
+        self.selection.unwrap_or_else(|| self.select_block())
+    }
+
+    /// Picks the block in use again, once the save index or the checksums of the blocks changed.
+    fn refresh_selection(&mut self) {
+        self.selection = Some(self.select_block());
+    }
+
+    fn select_block(&self) -> BlockSelection {
+        let Some(game_save_b) = &self.game_save_b else {
+            return BlockSelection::SingleBlock;
+        };
+
+        let status_a = self.block_status(&self.game_save_a);
+        let status_b = self.block_status(game_save_b);
+
+        match (status_a, status_b) {
+            (BlockStatus::Valid, BlockStatus::Valid) => match self.most_recent_block() {
+                SaveBlock::A => BlockSelection::ANewer,
+                SaveBlock::B => BlockSelection::BNewer,
+            },
+            (BlockStatus::Valid, BlockStatus::Empty) => BlockSelection::BEmpty,
+            (BlockStatus::Valid, BlockStatus::Corrupt) => BlockSelection::BCorrupt,
+            (BlockStatus::Empty, BlockStatus::Valid) => BlockSelection::AEmpty,
+            (BlockStatus::Corrupt, BlockStatus::Valid) => BlockSelection::ACorrupt,
+            _ => {
+                let valid_a = self.validate_block(&self.game_save_a).is_ok();
+                let valid_b = self.validate_block(game_save_b).is_ok();
+
+                match (valid_a, valid_b) {
+                    (true, true) => BlockSelection::BothCorrupt(self.most_recent_block()),
+                    (false, true) => BlockSelection::BothCorrupt(SaveBlock::B),
+                    _ => BlockSelection::BothCorrupt(SaveBlock::A),
+                }
+            }
+        }
+    }
+
+    fn current_block(&self) -> SaveBlock {
+        self.block_selection().block()
+    }
+
+    /// Compares the save index of both blocks the same way the game does.
+    ///
+    /// The index wrapping around from 0xFFFFFFFF to 0 is the only overflow the game accounts for.
+    fn most_recent_block(&self) -> SaveBlock {
+        let Some(game_save_b) = &self.game_save_b else {
+            return SaveBlock::A;
+        };
+
         let save_index_a = self.game_save_a[0].save_index(&self.data);
-        let save_index_b = self.game_save_b[0].save_index(&self.data);
+        let save_index_b = game_save_b[0].save_index(&self.data);
+
+        let wrapped = (save_index_a == u32::MAX && save_index_b == 0)
+            || (save_index_a == 0 && save_index_b == u32::MAX);
+
+        let b_newer = if wrapped {
+            save_index_a.wrapping_add(1) < save_index_b.wrapping_add(1)
+        } else {
+            save_index_a < save_index_b
+        };
 
-        if save_index_a == u32::MAX {
-            return &self.game_save_b;
+        if b_newer {
+            SaveBlock::B
+        } else {
+            SaveBlock::A
         }
-        if save_index_a > save_index_b {
-            return &self.game_save_a;
+    }
+
+    fn block_status(&self, block: &[Section]) -> BlockStatus {
+        // An erased block has no signature at all
+        if block
+            .iter()
+            .all(|section| section.signature(&self.data) != SIGNATURE_MAGIC_NUMBER)
+        {
+            return BlockStatus::Empty;
         }
 
-        &self.game_save_b
+        let checksums_valid = block
+            .iter()
+            .all(|section| section.verify_checksum(&self.data).is_ok());
+
+        if self.validate_block(block).is_ok() && checksums_valid {
+            BlockStatus::Valid
+        } else {
+            BlockStatus::Corrupt
+        }
     }
 
-    /// Retrieves the game code, which identifies the version of the game (e.g., Ruby, Sapphire, Emerald).
-    ///
-    /// The game code is stored in the Trainer Info section. For example:
-    /// - `0x00000000`: Ruby/Sapphire
-    /// - `0x00000001`: FireRed/LeafGreen
-    /// - Any other value: Emerald
+    /// Returns a slice of sections corresponding to the most recent save block.
+    fn current_save(&self) -> &[Section] {
+        match (self.current_block(), &self.game_save_b) {
+            (SaveBlock::B, Some(game_save_b)) => game_save_b,
+            _ => &self.game_save_a,
+        }
+    }
+
+    fn block_sections(&self, block: SaveBlock) -> Option<&[Section]> {
+        match block {
+            SaveBlock::A => Some(&self.game_save_a),
+            SaveBlock::B => self
+                .game_save_b
+                .as_ref()
+                .map(|sections| sections.as_slice()),
+        }
+    }
+
+    /// Returns the sections of the block the next save is written to, the older block. 64 KiB
+    /// saves only hold one block, which gets overwritten.
+    fn next_save(&self) -> &[Section] {
+        match (self.current_block(), &self.game_save_b) {
+            (SaveBlock::A, Some(game_save_b)) => game_save_b,
+            _ => &self.game_save_a,
+        }
+    }
+
+    /// Checks the structure of a game save block.
     ///
-    /// Returns the game code or an error if the Trainer Info section is missing.
-    fn get_game_code(&self) -> Result<u32, SaveDataError> {
-        let section = self
-            .get_section(SectionID::TrainerInfo)
-            .ok_or(SaveDataError::SectionNotFound(SectionID::TrainerInfo))?;
-        let section_data_buffer = section.data(&self.data);
-        Ok(LittleEndian::read_u32(
-            &section_data_buffer[0x00AC..0x00AC + 4],
-        ))
+    /// Every section must carry the signature, a known section ID that appears only once in the
+    /// block and the same save index as the rest of the block.
+    fn validate_block(&self, block: &[Section]) -> Result<(), SaveDataError> {
+        let save_index = block[0].save_index(&self.data);
+        let mut found = [false; NUMBER_GAME_SAVE_SECTIONS];
+
+        for section in block {
+            let signature = section.signature(&self.data);
+            if signature != SIGNATURE_MAGIC_NUMBER {
+                return Err(SaveDataError::InvalidSignature {
+                    offset: section.offset(),
+                    found: signature,
+                });
+            }
+
+            let id = section.id(&self.data);
+            if id == SectionID::NA {
+                return Err(SaveDataError::InvalidSectionID {
+                    offset: section.offset(),
+                    found: section.raw_id(&self.data),
+                });
+            }
+
+            // With no duplicates, 14 valid IDs means every section is present
+            let index = i32::from(id) as usize;
+            if found[index] {
+                return Err(SaveDataError::DuplicateSection(id));
+            }
+            found[index] = true;
+
+            let section_save_index = section.save_index(&self.data);
+            if section_save_index != save_index {
+                return Err(SaveDataError::SaveIndexMismatch {
+                    expected: save_index,
+                    found: section_save_index,
+                });
+            }
+        }
+
+        Ok(())
     }
 
-    fn get_section(&self, id: SectionID) -> Option<Section> {
+    fn get_section(&self, id: SectionID) -> Result<Section, SaveDataError> {
+        // An empty save (SaveFile::default) has no sections to look into
+        if self.is_empty() {
+            return Err(SaveDataError::InvalidFileSize(0));
+        }
+
         let current_save = self.current_save();
 
         current_save
             .iter()
             .find(|section| section.id(&self.data) == id)
             .copied()
+            .ok_or(SaveDataError::SectionNotFound(id))
+    }
+}
+
+/// Adds up the data as little-endian 32-bit words, then adds the upper 16 bits of the sum to the
+/// lower 16 bits.
+fn checksum(data: &[u8]) -> u16 {
+    let mut checksum: u32 = 0;
+
+    for chunk in data.chunks(4) {
+        checksum = checksum.wrapping_add(LittleEndian::read_u32(chunk));
+    }
+
+    // sum upper and lower bits
+    ((checksum & 0xFFFF) as u16).wrapping_add((checksum >> 16) as u16)
+}
+
+/// Lays out the 14 sections of a game save block starting at `offset`.
+fn game_save_sections(offset: usize) -> [Section; NUMBER_GAME_SAVE_SECTIONS] {
+    let mut game_save: [Section; NUMBER_GAME_SAVE_SECTIONS] =
+        [Section::default(); NUMBER_GAME_SAVE_SECTIONS];
+
+    for (i, section) in game_save.iter_mut().enumerate() {
+        *section = Section {
+            offset: offset + (i * SECTION_SIZE),
+            size: SECTION_SIZE,
+        };
     }
+
+    game_save
 }
 
 /// The Pokémon save file is divided into 14 sections, each corresponding to a specific aspect of the game.
@@ -512,9 +1837,19 @@ impl Section {
 
     /// Retrieves the section ID, which identifies the section's purpose (e.g., Trainer Info, PC Buffer A).
     fn id(&self, buffer: &[u8]) -> SectionID {
+        self.raw_id(buffer).into()
+    }
+
+    /// Retrieves the section ID as stored in the footer.
+    fn raw_id(&self, buffer: &[u8]) -> u16 {
+        let section_buffer = &buffer[self.offset..self.offset + self.size];
+        LittleEndian::read_u16(&section_buffer[0x0FF4..0x0FF6])
+    }
+
+    /// Retrieves the signature, a magic number (0x08012025) used to validate the section.
+    fn signature(&self, buffer: &[u8]) -> u32 {
         let section_buffer = &buffer[self.offset..self.offset + self.size];
-        let id = LittleEndian::read_u16(&section_buffer[0x0FF4..0x0FF6]);
-        id.into()
+        LittleEndian::read_u32(&section_buffer[0x0FF8..0x0FFC])
     }
 
     /// Reads the data of the section.
@@ -533,6 +1868,38 @@ impl Section {
         self.offset
     }
 
+    /// Writes the section ID, signature and save index into the footer.
+    fn write_footer(&self, buffer: &mut [u8], id: SectionID, save_index: u32) {
+        let section_buffer = &mut buffer[self.offset..self.offset + self.size];
+
+        section_buffer[0x0FF4..0x0FF6].copy_from_slice(&(i32::from(id) as u16).to_le_bytes());
+        section_buffer[0x0FF8..0x0FFC].copy_from_slice(&SIGNATURE_MAGIC_NUMBER.to_le_bytes());
+        section_buffer[0x0FFC..0x1000].copy_from_slice(&save_index.to_le_bytes());
+    }
+
+    /// Computes the checksum of the section over the data size of its section ID.
+    fn checksum(&self, buffer: &[u8]) -> u16 {
+        checksum(&self.data(buffer)[..self.id(buffer).data_size()])
+    }
+
+    /// Retrieves the checksum stored in the footer.
+    fn stored_checksum(&self, buffer: &[u8]) -> u16 {
+        let section_buffer = &buffer[self.offset..self.offset + self.size];
+        LittleEndian::read_u16(&section_buffer[0x0FF6..0x0FF8])
+    }
+
+    /// Compares the stored checksum against the one computed from the section data.
+    fn verify_checksum(&self, buffer: &[u8]) -> Result<(), SaveDataError> {
+        let expected = self.checksum(buffer);
+        let found = self.stored_checksum(buffer);
+
+        if expected != found {
+            return Err(SaveDataError::ChecksumMismatch { expected, found });
+        }
+
+        Ok(())
+    }
+
     /// Updates the checksum of the section to reflect changes in the data.
     /// Used to validate the integrity of saved data.
     /// A 16-bit checksum generated by adding up bytes from the section. The algorithm is as follows:
@@ -540,17 +1907,11 @@ impl Section {
     /// -Read 4 bytes at a time as 32-bit word (little-endian) and add it to the variable.
     /// -Take the upper 16 bits of the result, and add them to the lower 16 bits of the result.
     /// -This new 16-bit value is the checksum.
+    ///
+    /// Only the data size of the section ID is summed: 3884 bytes for Trainer Info, 2000 bytes for
+    /// PC buffer I and 3968 bytes for every other section.
     fn write_checksum(&self, buffer: &mut [u8]) -> Result<(), SaveDataError> {
-        let mut checksum: u32 = 0;
-        let data = self.data(buffer);
-
-        for chunk in data.chunks(4) {
-            let (sum, _) = checksum.overflowing_add(LittleEndian::read_u32(chunk));
-            checksum = sum;
-        }
-
-        // sum opper and lower bits
-        let (checksum, _) = ((checksum & 0xFFFF) as u16).overflowing_add((checksum >> 16) as u16);
+        let checksum = self.checksum(buffer);
 
         let section_buffer = &mut buffer[self.offset..self.offset + self.size];
 
@@ -596,29 +1957,63 @@ impl PCBuffer {
 
     /// Retrieves all Pokémon stored in a specific PC box.
     /// Each PC box is a fixed-size chunk of the PC Buffer, containing 30 Pokémon slots.
-    fn pc_box(&self, number: usize) -> Vec<Pokemon> {
-        let mut boxes = self.data[0x0004..0x8344].chunks(2400);
-        let pc = boxes.nth(number).expect("Expected value but found None");
-        let mut list: Vec<Pokemon> = vec![];
+    fn pc_box(&self, number: usize) -> Result<[Pokemon; PC_BOX_SLOTS], SaveDataError> {
+        let mut pc = [Pokemon::default(); PC_BOX_SLOTS];
 
-        for (i, pokemon) in pc.chunks(80).enumerate() {
+        for (i, pokemon) in pc.iter_mut().enumerate() {
             // data_offset + pc box offset + slot offset
-            let offset = 0x0004 + (number * 2400) + (i * 80);
-            let pokemon = Pokemon::new(offset, pokemon);
-            list.push(pokemon);
+            let offset = PCBuffer::slot_offset(number, i)?;
+            *pokemon = Pokemon::new(offset, self.read(offset, PC_POKEMON_SIZE)?);
+            pokemon.set_slot(SlotRef::Box(number, i));
         }
 
-        list
+        Ok(pc)
     }
 
-    /// Saves a Pokémon back into the PC Buffer and updates the relevant sections.
-    ///
-    /// # Arguments
-    /// - `pokemon`: The Pokémon to save.
-    /// - `buffer`: The save file data buffer to update.
-    fn save_pokemon(&mut self, pokemon: Pokemon, buffer: &mut [u8]) -> Result<(), SaveDataError> {
-        let offset = pokemon.offset();
-        self.data[offset..offset + 80].copy_from_slice(&pokemon.raw_data()[..80]);
+    fn box_number(number: usize) -> Result<usize, SaveDataError> {
+        if number >= PC_BOX_COUNT {
+            return Err(SaveDataError::InvalidBox(number));
+        }
+
+        Ok(number)
+    }
+
+    /// Offset of a box slot in the PC Buffer data.
+    fn slot_offset(number: usize, slot: usize) -> Result<usize, SaveDataError> {
+        PCBuffer::box_number(number)?;
+
+        if slot >= PC_BOX_SLOTS {
+            return Err(SaveDataError::InvalidBoxSlot(slot));
+        }
+
+        Ok(PC_BOXES_OFFSET + number * PC_BOX_SIZE + slot * PC_POKEMON_SIZE)
+    }
+
+    /// Raw data of the Pokémon of a box slot.
+    fn slot_data(&self, number: usize, slot: usize) -> Result<&[u8], SaveDataError> {
+        self.read(PCBuffer::slot_offset(number, slot)?, PC_POKEMON_SIZE)
+    }
+
+    fn read(&self, offset: usize, size: usize) -> Result<&[u8], SaveDataError> {
+        self.data
+            .get(offset..offset + size)
+            .ok_or(SaveDataError::InvalidDataLength {
+                expected: offset + size,
+                found: self.data.len(),
+            })
+    }
+
+    /// Writes data into the PC Buffer and copies it back into the sections.
+    fn write(
+        &mut self,
+        offset: usize,
+        data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), SaveDataError> {
+        self.data
+            .get_mut(offset..offset + data.len())
+            .ok_or(SaveDataError::InvalidOffset(offset))?
+            .copy_from_slice(data);
 
         // Update each section of the PC Buffer and recalculate checksums.
         for (i, section) in self.data.chunks(PC_BUFFER_SECTION_SIZE).enumerate() {
@@ -646,11 +2041,46 @@ impl PCBuffer {
 ///
 /// The Trainer ID is split into two components:
 /// - The **public ID** (lower 16 bits), which is visible in-game.
-/// - The **private ID** (upper 16 bits), which is used internally for certain mechanics (e.g., shiny Pokémon).
-#[derive(Debug, Copy, Clone)]
+/// - The **secret ID** (upper 16 bits), which is used internally for certain mechanics (e.g., shiny Pokémon).
+///
+/// Displayed as both 5-digit IDs, public first: `01234/56789`.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
 pub struct TrainerID {
     public: u16,
-    private: u16,
+    secret: u16,
+}
+
+impl TrainerID {
+    pub fn new(public: u16, secret: u16) -> Self {
+        TrainerID { public, secret }
+    }
+
+    pub fn public(&self) -> u16 {
+        self.public
+    }
+
+    pub fn secret(&self) -> u16 {
+        self.secret
+    }
+
+    /// XOR of both IDs with both halves of the personality value. Pokémon with a shiny value
+    /// below 8 are shiny.
+    pub fn shiny_value(&self, personality_value: u32) -> u16 {
+        self.public
+            ^ self.secret
+            ^ (personality_value >> 16) as u16
+            ^ (personality_value & 0xFFFF) as u16
+    }
+
+    pub fn is_shiny(&self, personality_value: u32) -> bool {
+        self.shiny_value(personality_value) < 8
+    }
+}
+
+impl fmt::Display for TrainerID {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:05}/{:05}", self.public, self.secret)
+    }
 }
 
 impl From<[u8; 4]> for TrainerID {
@@ -659,19 +2089,39 @@ impl From<[u8; 4]> for TrainerID {
         // Since it's little endian the lower 16 bit are the first 2 bytes
         TrainerID {
             public: LittleEndian::read_u16(&buffer[..2]),
-            private: LittleEndian::read_u16(&buffer[2..]),
+            secret: LittleEndian::read_u16(&buffer[2..]),
         }
     }
 }
 
-impl Into<Vec<u8>> for TrainerID {
-    fn into(self) -> Vec<u8> {
-        let buffer: Vec<u8> = vec![0, 0, 0, 0];
+impl From<TrainerID> for [u8; 4] {
+    fn from(trainer_id: TrainerID) -> Self {
+        let mut buffer = [0; 4];
+        LittleEndian::write_u16(&mut buffer[..2], trainer_id.public);
+        LittleEndian::write_u16(&mut buffer[2..], trainer_id.secret);
 
         buffer
     }
 }
 
+impl From<TrainerID> for Vec<u8> {
+    fn from(trainer_id: TrainerID) -> Self {
+        <[u8; 4]>::from(trainer_id).to_vec()
+    }
+}
+
+impl From<u32> for TrainerID {
+    fn from(id: u32) -> Self {
+        id.to_le_bytes().into()
+    }
+}
+
+impl From<TrainerID> for u32 {
+    fn from(trainer_id: TrainerID) -> Self {
+        u32::from_le_bytes(trainer_id.into())
+    }
+}
+
 /// Enum representing the ID of a save file section.
 /// Specifies the save data being represented
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Default)]
@@ -694,6 +2144,18 @@ pub enum SectionID {
     NA,
 }
 
+impl SectionID {
+    /// Number of bytes of the section used by the game, which is also the number of bytes covered
+    /// by the checksum.
+    pub fn data_size(&self) -> usize {
+        match self {
+            SectionID::TrainerInfo => TRAINER_INFO_SECTION_SIZE,
+            SectionID::PCbufferI => PC_BUFFER_I_SECTION_SIZE,
+            _ => DEFAULT_SECTION_SIZE,
+        }
+    }
+}
+
 impl From<u16> for SectionID {
     fn from(id: u16) -> Self {
         match id {
@@ -738,7 +2200,109 @@ impl From<SectionID> for i32 {
     }
 }
 
-#[derive(Debug, Copy, Clone, Default)]
+/// Identifies one of the two game save blocks.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SaveBlock {
+    /// Game save A, at 0x000000.
+    A,
+    /// Game save B, at 0x00E000.
+    B,
+}
+
+/// Reason a game save block is in use, see `SaveFile::block_selection`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BlockSelection {
+    /// Both blocks are valid and A holds the most recent save.
+    ANewer,
+    /// Both blocks are valid and B holds the most recent save.
+    BNewer,
+    /// Block A is corrupt, B is used.
+    ACorrupt,
+    /// Block B is corrupt, A is used.
+    BCorrupt,
+    /// Block A is erased, B is used.
+    AEmpty,
+    /// Block B is erased, as in a game saved only once, A is used.
+    BEmpty,
+    /// The file only holds block A (64 KiB save).
+    SingleBlock,
+    /// Neither block is valid, the game would report the save as corrupted.
+    BothCorrupt(SaveBlock),
+    /// Block picked through `SaveFile::view_block`.
+    Selected(SaveBlock),
+}
+
+impl BlockSelection {
+    /// The block in use.
+    pub fn block(&self) -> SaveBlock {
+        match self {
+            BlockSelection::ANewer
+            | BlockSelection::BCorrupt
+            | BlockSelection::BEmpty
+            | BlockSelection::SingleBlock => SaveBlock::A,
+            BlockSelection::BNewer | BlockSelection::ACorrupt | BlockSelection::AEmpty => {
+                SaveBlock::B
+            }
+            BlockSelection::BothCorrupt(block) | BlockSelection::Selected(block) => *block,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum BlockStatus {
+    Valid,
+    Corrupt,
+    Empty,
+}
+
+/// Summary of a game save block, see `SaveFile::blocks`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct BlockInfo {
+    pub block: SaveBlock,
+    pub save_index: u32,
+    /// Whether the game would accept the block: correct structure and checksums.
+    pub valid: bool,
+    /// Whether the block is the one read and written by the `SaveFile`.
+    pub current: bool,
+}
+
+/// Checksum verification result of a single section.
+#[derive(Debug)]
+pub struct SectionChecksum {
+    pub id: SectionID,
+    /// `SaveDataError::ChecksumMismatch` when the stored checksum is wrong.
+    pub result: Result<(), SaveDataError>,
+}
+
+/// Checksum verification results of every section of a game save block, ordered by section ID.
+#[derive(Debug)]
+pub struct ChecksumReport {
+    pub block: SaveBlock,
+    pub sections: Vec<SectionChecksum>,
+}
+
+impl ChecksumReport {
+    /// Whether every section of the block has a correct checksum.
+    pub fn is_valid(&self) -> bool {
+        self.sections.iter().all(|section| section.result.is_ok())
+    }
+}
+
+/// Slot holding a Pokémon in the save file.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SlotRef {
+    /// Party slot, from 0 to 5.
+    Party(usize),
+    /// PC box, from 0 to 13, and slot of the box, from 0 to 29.
+    Box(usize, usize),
+    /// Day Care slot, 0 or 1.
+    Daycare(usize),
+}
+
+/// Kind of storage a Pokémon was saved to, replaced by the slot carried by each Pokémon.
+#[deprecated(since = "0.2.0", note = "use `SlotRef` along with `Pokemon::slot`")]
+#[allow(deprecated)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
 pub enum StorageType {
     PC,
     Party,
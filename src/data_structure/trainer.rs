@@ -0,0 +1,131 @@
+//! Profile of the player, read from the Trainer Info and Team / items sections.
+//!
+//!| Offset | Size | Contents                     | Section      |
+//!|--------|------|------------------------------|--------------|
+//!| 0x0000 |   7  |  Name                        | Trainer info |
+//!| 0x0008 |   1  |  Gender                      | Trainer info |
+//!| 0x000A |   4  |  Trainer ID                  | Trainer info |
+//!| 0x000E |   5  |  Play time                   | Trainer info |
+//!|   -    |   4  |  Money (XORed with the key)  | Team / items |
+//!|   -    |   2  |  Coins (XORed with the key)  | Team / items |
+//!
+//! The offsets of money and coins depend on the game version, see the `layout` module.
+use byteorder::{ByteOrder, LittleEndian};
+
+use crate::data_structure::character_set::{get_char, try_get_code};
+use crate::data_structure::save_data::{SaveDataError, TrainerID};
+
+pub(crate) const MAX_MONEY: u32 = 999999;
+pub(crate) const MAX_COINS: u16 = 9999;
+const MAX_PLAY_TIME_HOURS: u16 = 999;
+const NAME_TERMINATOR: u8 = 0xFF;
+
+/// Player profile.
+#[derive(Debug, Clone)]
+pub struct Trainer {
+    pub name: String,
+    pub gender: TrainerGender,
+    pub id: TrainerID,
+    pub play_time: PlayTime,
+    pub money: u32,
+    pub coins: u16,
+}
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrainerGender {
+    #[default]
+    Male,
+    Female,
+}
+
+impl From<u8> for TrainerGender {
+    fn from(gender: u8) -> Self {
+        // The games only tell apart 0 from anything else
+        match gender {
+            0 => TrainerGender::Male,
+            _ => TrainerGender::Female,
+        }
+    }
+}
+
+impl From<TrainerGender> for u8 {
+    fn from(gender: TrainerGender) -> Self {
+        match gender {
+            TrainerGender::Male => 0,
+            TrainerGender::Female => 1,
+        }
+    }
+}
+
+/// Time played, as counted by the game. The clock stops at 999:59:59.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlayTime {
+    pub hours: u16,
+    pub minutes: u8,
+    pub seconds: u8,
+    /// Frames of the current second, the game runs at 60 frames per second.
+    pub frames: u8,
+}
+
+impl PlayTime {
+    pub(crate) fn new(buffer: &[u8]) -> Self {
+        PlayTime {
+            hours: LittleEndian::read_u16(&buffer[0..2]),
+            minutes: buffer[2],
+            seconds: buffer[3],
+            frames: buffer[4],
+        }
+    }
+
+    /// Checks every component is within the range the game counts.
+    pub(crate) fn raw_data(&self) -> Result<[u8; 5], SaveDataError> {
+        out_of_range(self.hours as u32, MAX_PLAY_TIME_HOURS as u32)?;
+        out_of_range(self.minutes as u32, 59)?;
+        out_of_range(self.seconds as u32, 59)?;
+        out_of_range(self.frames as u32, 59)?;
+
+        let mut buffer = [0; 5];
+        LittleEndian::write_u16(&mut buffer[0..2], self.hours);
+        buffer[2] = self.minutes;
+        buffer[3] = self.seconds;
+        buffer[4] = self.frames;
+
+        Ok(buffer)
+    }
+}
+
+pub(crate) fn out_of_range(value: u32, max: u32) -> Result<(), SaveDataError> {
+    if value > max {
+        return Err(SaveDataError::ValueOutOfRange { value, max });
+    }
+
+    Ok(())
+}
+
+/// Decodes a name up to its terminator.
+pub(crate) fn decode_name(buffer: &[u8]) -> String {
+    buffer
+        .iter()
+        .take_while(|c| **c != NAME_TERMINATOR)
+        .map(|c| get_char(*c as usize))
+        .collect()
+}
+
+/// Encodes a name into `size` bytes, padded with the terminator.
+pub(crate) fn encode_name(name: &str, size: usize) -> Result<Vec<u8>, SaveDataError> {
+    let mut buffer = name
+        .chars()
+        .map(|c| try_get_code(&c.to_string()))
+        .collect::<Result<Vec<u8>, _>>()?;
+
+    if buffer.len() > size {
+        return Err(SaveDataError::InvalidDataLength {
+            expected: size,
+            found: buffer.len(),
+        });
+    }
+
+    buffer.resize(size, NAME_TERMINATOR);
+
+    Ok(buffer)
+}
@@ -0,0 +1,121 @@
+//! Game variables, the 16-bit values of the Game State section used by the game scripts to keep
+//! track of counters and progress, such as the collected volcanic ash or the lottery number.
+//!
+//! Variables are numbered from 0x4000, variable `n` being the little-endian word at
+//! `(n - 0x4000) * 2` of the array. The location of the array depends on the game version, see
+//! the `layout` module.
+//!
+//! Ruby/Sapphire and Emerald share their variable numbers, FireRed/LeafGreen use others. The
+//! enums below name the well-known ones.
+//!
+//! # Example
+//!
+//! ```rust no_run
+//! use pk_edit::SaveFile;
+//! use pk_edit::data_structure::vars::EmeraldVar;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let buffer: Vec<u8> = vec![];
+//! let mut save_file = SaveFile::parse(&buffer)?;
+//!
+//! let ash = save_file.var(EmeraldVar::AshGatherCount)?;
+//! save_file.set_var(EmeraldVar::AshGatherCount, ash + 100)?;
+//! # Ok(())
+//! # }
+//! ```
+use byteorder::{ByteOrder, LittleEndian};
+
+use crate::data_structure::save_data::SaveDataError;
+
+const VARS_START: u16 = 0x4000;
+
+/// Copy of the variable array of a save file, see `SaveFile::vars`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vars {
+    data: Vec<u8>,
+}
+
+impl Vars {
+    pub(crate) fn new(data: Vec<u8>) -> Self {
+        Vars { data }
+    }
+
+    pub(crate) fn raw_data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Number of variables in the array.
+    pub fn len(&self) -> usize {
+        self.data.len() / 2
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn get(&self, var: impl Into<u16>) -> Result<u16, SaveDataError> {
+        let offset = self.offset(var.into())?;
+        Ok(LittleEndian::read_u16(&self.data[offset..offset + 2]))
+    }
+
+    pub fn set(&mut self, var: impl Into<u16>, value: u16) -> Result<(), SaveDataError> {
+        let offset = self.offset(var.into())?;
+        LittleEndian::write_u16(&mut self.data[offset..offset + 2], value);
+
+        Ok(())
+    }
+
+    fn offset(&self, var: u16) -> Result<usize, SaveDataError> {
+        let last = VARS_START as usize + self.len().saturating_sub(1);
+
+        if var < VARS_START || var as usize > last {
+            return Err(SaveDataError::ValueOutOfRange {
+                value: var as u32,
+                max: last as u32,
+            });
+        }
+
+        Ok((var - VARS_START) as usize * 2)
+    }
+}
+
+/// Variables of Ruby/Sapphire and Emerald.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum HoennVar {
+    RepelStepCount = 0x4021,
+    /// 0 for Treecko, 1 for Torchic, 2 for Mudkip.
+    StarterMon = 0x4023,
+    /// Trick House puzzles cleared.
+    TrickHouseLevel = 0x4044,
+    /// 0x0302 once the National Pokédex is unlocked.
+    NationalDex = 0x4046,
+    /// Volcanic ash collected for the glass workshop.
+    AshGatherCount = 0x4048,
+    /// Lower half of the Lottery Corner number.
+    LotteryRandomLow = 0x404B,
+    /// Upper half of the Lottery Corner number.
+    LotteryRandomHigh = 0x404C,
+}
+
+pub type RubySapphireVar = HoennVar;
+pub type EmeraldVar = HoennVar;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FireRedLeafGreenVar {
+    /// Starter picked in Oak's lab.
+    StarterMon = 0x4031,
+    /// 0x6258 once the National Pokédex is unlocked.
+    NationalDex = 0x404E,
+}
+
+impl From<HoennVar> for u16 {
+    fn from(var: HoennVar) -> Self {
+        var as u16
+    }
+}
+
+impl From<FireRedLeafGreenVar> for u16 {
+    fn from(var: FireRedLeafGreenVar) -> Self {
+        var as u16
+    }
+}
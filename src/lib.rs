@@ -8,7 +8,7 @@
 //! use std::io::Read;
 //! use std::error::Error;
 //! use std::io::BufReader;
-//! use pk_edit::{SaveFile, Pokemon};
+//! use pk_edit::{SaveFile, Pocket};
 //!
 //! fn main() -> Result<(), Box<dyn Error>> {
 //!     let mut buffer = Vec::new();
@@ -16,21 +16,21 @@
 //!     let mut buf_reader = BufReader::new(file);
 //!     buf_reader.read_to_end(&mut buffer)?;
 //!
-//!     let save_file: SaveFile = SaveFile::new(&buffer);
+//!     let save_file: SaveFile = SaveFile::parse(&buffer)?;
 //!
-//!     let party = save_file.get_party();
+//!     let party = save_file.get_party()?;
 //!
-//!     let box1 = save_file.pc_box(0);
+//!     let box1 = save_file.pc_box(0)?;
 //!
-//!     let item_bag = save_file.item_pocket();
+//!     let item_bag = save_file.pocket(Pocket::Items)?;
 //!
-//!     let ball_bag = save_file.ball_pocket();
+//!     let ball_bag = save_file.pocket(Pocket::Pokeballs)?;
 //!
-//!     let berry_bag = save_file.berry_pocket();
+//!     let berry_bag = save_file.pocket(Pocket::Berries)?;
 //!
-//!     let tm_bag = save_file.tm_pocket();
+//!     let tm_bag = save_file.pocket(Pocket::Tms)?;
 //!
-//!     let key_bag = save_file.key_pocket();
+//!     let key_bag = save_file.pocket(Pocket::Key)?;
 //!
 //!
 //!     Ok(())
@@ -49,6 +49,11 @@ pub use crate::data_structure::pokemon::Evolution;
 #[doc(hidden)]
 pub use crate::data_structure::save_data::SaveFile;
 #[doc(hidden)]
+pub use crate::data_structure::save_data::SlotRef;
+#[doc(hidden)]
+#[allow(deprecated)]
 pub use crate::data_structure::save_data::StorageType;
 #[doc(hidden)]
 pub use crate::data_structure::save_data::Pocket;
+#[doc(hidden)]
+pub use crate::data_structure::save_data::GameVersion;
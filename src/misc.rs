@@ -305,6 +305,20 @@ pub fn find_item(id_g3: usize) -> Result<String> {
     res
 }
 
+pub fn item_type(id_g3: usize) -> Result<String> {
+    let conn = Connection::open("pk_edit.db")?;
+
+    let res = conn.query_row(
+        "SELECT type FROM Items WHERE id_g3 = ?1",
+        [id_g3],
+        |row| row.get(0),
+    );
+
+    let _ = conn.close();
+
+    res
+}
+
 pub fn item_id(name: &str) -> Result<usize> {
     let conn = Connection::open("pk_edit.db")?;
     let name = match_item_name(name);
@@ -1,6 +1,19 @@
 #[cfg(test)]
 mod tests {
+    use byteorder::{ByteOrder, LittleEndian};
+
+    use crate::data_structure::flags::{self, emerald, fire_red_leaf_green, ruby_sapphire};
+    use crate::data_structure::hall_of_fame::{HallOfFameMon, HallOfFameTeam};
+    use crate::data_structure::item::{Item, PocketViolation, SortOrder};
+    use crate::data_structure::layout::{self, Field};
+    use crate::data_structure::pokedex::NATIONAL_DEX_COUNT;
     use crate::data_structure::pokemon::{gen_pokemon_from_species, Pokemon, Pokerus};
+    use crate::data_structure::save_data::{
+        BlockInfo, BlockSelection, GameVersion, Pocket, SaveBlock, SaveDataError, SaveFile,
+        SaveVariant, SectionID, SlotRef, Trailer, TrainerID, Wallpaper,
+    };
+    use crate::data_structure::trainer::{PlayTime, TrainerGender};
+    use crate::data_structure::vars::{EmeraldVar, FireRedLeafGreenVar, RubySapphireVar};
 
     const TORCHIK: [u8; 100] = [
         101, 231, 167, 198, 154, 166, 220, 6, 206, 201, 204, 189, 194, 195, 189, 255, 1, 0, 2, 2,
@@ -18,6 +31,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn is_egg() {
         let torchik = Pokemon::new(0, &TORCHIK);
         let is_egg = torchik.is_egg();
@@ -25,6 +39,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::unnecessary_cast)]
     fn moves() {
         let torchik = Pokemon::new(0, &TORCHIK);
         let moves: Vec<(String, String, u8, u8)> = vec![
@@ -53,15 +68,1715 @@ mod tests {
 
     #[test]
     fn gen_p() {
-        let mut torchik = Pokemon::new(0, &TORCHIK);
+        let torchik = Pokemon::new(0, &TORCHIK);
         let ot_name = torchik.ot_name();
         let ot_id: Vec<u8> = torchik.ot_id().into();
         println!("{}", torchik);
 
         let bulbasour =
-            gen_pokemon_from_species(&mut torchik, "Bulbasaur", &ot_name.as_bytes(), &ot_id);
+            gen_pokemon_from_species(torchik, "Bulbasaur", ot_name.as_bytes(), &ot_id).unwrap();
         println!("{}", bulbasour);
 
-        assert_eq!(true, true);
+        assert_eq!("Bulbasaur", bulbasour.species());
+        assert_eq!(torchik.ot_id(), bulbasour.ot_id());
+    }
+
+    const SECTION_SIZE: usize = 0x1000;
+    const SIGNATURE: u32 = 0x08012025;
+
+    /// Game checksum, summing `size` bytes of the section data.
+    fn section_checksum(data: &[u8], size: usize) -> u16 {
+        let sum = data[..size].chunks(4).fold(0u32, |sum, chunk| {
+            sum.wrapping_add(LittleEndian::read_u32(chunk))
+        });
+
+        ((sum & 0xFFFF) as u16).wrapping_add((sum >> 16) as u16)
+    }
+
+    fn section_data_size(id: usize) -> usize {
+        match id {
+            0 => 3884,
+            13 => 2000,
+            _ => 3968,
+        }
+    }
+
+    /// Writes a game save block at `offset` the way the game does, section `id` stored at
+    /// `(id + rotation) % 14`.
+    fn write_block(data: &mut [u8], offset: usize, save_index: u32, rotation: usize) {
+        for id in 0..14 {
+            let start = offset + ((id + rotation) % 14) * SECTION_SIZE;
+            let section = &mut data[start..start + SECTION_SIZE];
+
+            let checksum = section_checksum(section, section_data_size(id));
+            LittleEndian::write_u16(&mut section[0x0FF4..0x0FF6], id as u16);
+            LittleEndian::write_u16(&mut section[0x0FF6..0x0FF8], checksum);
+            LittleEndian::write_u32(&mut section[0x0FF8..0x0FFC], SIGNATURE);
+            LittleEndian::write_u32(&mut section[0x0FFC..0x1000], save_index);
+        }
+    }
+
+    const EMERALD_SECURITY_KEY: u32 = 0x1B2C_3D4E;
+
+    /// Fills the Trainer Info section at `offset` the way Emerald does: security key in the game
+    /// code slot and Battle Frontier data past the bytes used by Ruby/Sapphire.
+    fn write_emerald_trainer_info(data: &mut [u8], offset: usize) {
+        LittleEndian::write_u32(&mut data[offset + 0x00AC..], EMERALD_SECURITY_KEY);
+        data[offset + 0x0EE1] = 0xFF;
+    }
+
+    /// Builds an Emerald save with block A (index 10) as backup and block B (index 11) as current.
+    /// The first byte of the trainer name tells the blocks apart: 0xAA in A, 0xBB in B.
+    fn synthetic_save() -> Vec<u8> {
+        let mut data = vec![0; 0x20000];
+        data[0x0000] = 0xAA;
+        data[0xF000] = 0xBB;
+        write_emerald_trainer_info(&mut data, 0x0000);
+        write_emerald_trainer_info(&mut data, 0xF000);
+
+        write_block(&mut data, 0x0000, 10, 0);
+        write_block(&mut data, 0xE000, 11, 1);
+
+        data
+    }
+
+    /// Same as `synthetic_save` but every section is filled with data, including the unused bytes
+    /// past the data size of the section which the game leaves out of the checksum.
+    fn patterned_save() -> Vec<u8> {
+        let mut data = vec![0; 0x20000];
+
+        for (i, byte) in data.iter_mut().enumerate() {
+            if i % SECTION_SIZE < 0x0FF4 {
+                *byte = ((i * 31) >> 3) as u8;
+            }
+        }
+
+        write_block(&mut data, 0x0000, 10, 0);
+        write_block(&mut data, 0xE000, 11, 1);
+
+        data
+    }
+
+    /// Asserts every section of both blocks holds a valid checksum.
+    fn assert_checksums_valid(save: &SaveFile) {
+        assert!(save
+            .verify_checksums()
+            .iter()
+            .all(|report| report.is_valid()));
+    }
+
+    #[test]
+    fn parse_valid_save() {
+        let save = SaveFile::parse(&synthetic_save()).unwrap();
+
+        assert!(save.get_party().unwrap().is_empty());
+        assert_eq!(30, save.pc_box(13).unwrap().len());
+    }
+
+    #[test]
+    fn parse_invalid_size() {
+        let result = SaveFile::parse(&[0; 1024]);
+
+        assert!(matches!(result, Err(SaveDataError::InvalidFileSize(1024))));
+    }
+
+    #[test]
+    fn parse_blank_save() {
+        assert!(matches!(
+            SaveFile::parse(&vec![0xFF; 0x20000]),
+            Err(SaveDataError::InvalidSignature { .. })
+        ));
+        assert!(matches!(
+            SaveFile::parse(&vec![0; 0x10000]),
+            Err(SaveDataError::InvalidSignature { .. })
+        ));
+    }
+
+    #[test]
+    fn parse_erased_backup_block() {
+        let mut data = synthetic_save();
+        data[0xE000..0x1C000].fill(0xFF);
+
+        let save = SaveFile::parse(&data).unwrap();
+
+        assert!(save.ot_name().is_ok());
+    }
+
+    #[test]
+    fn parse_duplicate_section() {
+        let mut data = vec![0; 0x10000];
+        write_block(&mut data, 0x0000, 1, 0);
+        LittleEndian::write_u16(&mut data[0x1FF4..0x1FF6], 0);
+
+        assert!(matches!(
+            SaveFile::parse(&data),
+            Err(SaveDataError::DuplicateSection(_))
+        ));
+    }
+
+    #[test]
+    fn parse_save_index_mismatch() {
+        let mut data = vec![0; 0x10000];
+        write_block(&mut data, 0x0000, 1, 0);
+        LittleEndian::write_u32(&mut data[0x3FFC..0x4000], 2);
+
+        assert!(matches!(
+            SaveFile::parse(&data),
+            Err(SaveDataError::SaveIndexMismatch {
+                expected: 1,
+                found: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn invalid_box() {
+        let save = SaveFile::parse(&synthetic_save()).unwrap();
+
+        assert!(matches!(
+            save.pc_box(14),
+            Err(SaveDataError::InvalidBox(14))
+        ));
+    }
+
+    #[test]
+    fn empty_save_accessors() {
+        let save = SaveFile::default();
+
+        assert!(save.ot_id().is_err());
+        assert!(save.game_code().is_err());
+        assert!(save.pc_box(0).is_err());
+    }
+
+    #[test]
+    fn empty_save_blocks() {
+        let mut save = SaveFile::default();
+
+        assert!(matches!(
+            save.view_block(SaveBlock::A),
+            Err(SaveDataError::InvalidFileSize(0))
+        ));
+        assert!(matches!(
+            save.promote_backup(),
+            Err(SaveDataError::InvalidFileSize(0))
+        ));
+    }
+
+    #[test]
+    fn verify_checksums() {
+        let save = SaveFile::parse(&synthetic_save()).unwrap();
+        let reports = save.verify_checksums();
+
+        assert_eq!(2, reports.len());
+        assert!(reports.iter().all(|report| report.is_valid()));
+        assert_eq!(14, reports[1].sections.len());
+    }
+
+    #[test]
+    fn verify_checksums_mismatch() {
+        let mut data = synthetic_save();
+        // Team / items of block B, stored in the third slot because of the rotation
+        data[0xE000 + 0x2000 + 0x10] = 1;
+        // Bytes past the 3884 bytes of trainer info are not covered by the checksum
+        data[0x0F30] = 1;
+
+        let save = SaveFile::parse(&data).unwrap();
+        let reports = save.verify_checksums();
+
+        assert_eq!(SaveBlock::A, reports[0].block);
+        assert!(reports[0].is_valid());
+
+        assert_eq!(SaveBlock::B, reports[1].block);
+        assert!(!reports[1].is_valid());
+        for section in &reports[1].sections {
+            if section.id == SectionID::TeamItems {
+                assert!(matches!(
+                    section.result,
+                    Err(SaveDataError::ChecksumMismatch { .. })
+                ));
+            } else {
+                assert!(section.result.is_ok());
+            }
+        }
+    }
+
+    #[test]
+    fn update_checksums_round_trip() {
+        let data = patterned_save();
+        let mut save = SaveFile::parse(&data).unwrap();
+
+        save.update_checksums().unwrap();
+
+        assert_eq!(data, save.raw_data());
+    }
+
+    #[test]
+    fn save_pokemon_last_box_round_trip() {
+        let data = patterned_save();
+        let mut save = SaveFile::parse(&data).unwrap();
+
+        // Box 14 spans PC buffer H and PC buffer I, the smaller section
+        let pokemon = save.pc_box(13).unwrap()[29];
+        save.save_pokemon(pokemon.slot().unwrap(), pokemon).unwrap();
+
+        assert!(save.verify_checksums()[1].is_valid());
+        assert_eq!(data, save.raw_data());
+    }
+
+    #[test]
+    fn commit() {
+        let data = synthetic_save();
+        let mut save = SaveFile::parse(&data).unwrap();
+
+        save.commit().unwrap();
+        let committed = save.raw_data();
+
+        // Block B is kept untouched as the backup
+        assert_eq!(data[0xE000..0x1C000], committed[0xE000..0x1C000]);
+
+        // Block A holds the new save, rotated once more than block B
+        for slot in 0..14 {
+            let section = &committed[slot * SECTION_SIZE..(slot + 1) * SECTION_SIZE];
+            let id = (slot + 14 - 2) % 14;
+
+            assert_eq!(id as u16, LittleEndian::read_u16(&section[0x0FF4..]));
+            assert_eq!(12, LittleEndian::read_u32(&section[0x0FFC..]));
+        }
+
+        assert_eq!(0xBB, save.ot_name().unwrap()[0]);
+        assert_checksums_valid(&save);
+
+        // Committing again goes back to block B
+        save.commit().unwrap();
+        assert_eq!(committed[0x0000..0xE000], save.raw_data()[0x0000..0xE000]);
+        assert_eq!(13, LittleEndian::read_u32(&save.raw_data()[0xEFFC..]));
+    }
+
+    #[test]
+    fn commit_half_save() {
+        let mut data = vec![0; 0x10000];
+        write_block(&mut data, 0x0000, 1, 13);
+        let mut save = SaveFile::parse(&data).unwrap();
+
+        save.commit().unwrap();
+        let committed = save.raw_data();
+
+        assert_eq!(0, LittleEndian::read_u16(&committed[0x0FF4..]));
+        assert_eq!(2, LittleEndian::read_u32(&committed[0x0FFC..]));
+        assert!(SaveFile::parse(&committed).is_ok());
+    }
+
+    #[test]
+    fn parse_variants() {
+        let data = synthetic_save();
+
+        for (size, variant) in [
+            (0x20000, SaveVariant::Full),
+            (0x1C000, SaveVariant::Trimmed),
+            (0x10000, SaveVariant::Half),
+            (0xE000, SaveVariant::TrimmedHalf),
+        ] {
+            let save = SaveFile::parse(&data[..size]).unwrap();
+
+            assert_eq!(variant, save.variant());
+            assert_eq!(Trailer::None, save.trailer());
+            assert_eq!(&data[..size], save.raw_data().as_slice());
+        }
+
+        assert!(matches!(
+            SaveFile::parse(&data[..0x1E000]),
+            Err(SaveDataError::InvalidFileSize(0x1E000))
+        ));
+    }
+
+    #[test]
+    fn trimmed_save_commit() {
+        let mut save = SaveFile::parse(&synthetic_save()[..0x1C000]).unwrap();
+
+        save.commit().unwrap();
+        let committed = save.raw_data();
+
+        assert_eq!(0x1C000, committed.len());
+        assert_eq!(
+            SaveBlock::A,
+            SaveFile::parse(&committed)
+                .unwrap()
+                .block_selection()
+                .block()
+        );
+    }
+
+    #[test]
+    fn trailer_round_trip() {
+        let mut data = synthetic_save();
+        data.extend_from_slice(&[0x5A; 16]);
+        let mut save = SaveFile::parse(&data).unwrap();
+
+        assert_eq!(SaveVariant::Full, save.variant());
+        assert_eq!(Trailer::Rtc, save.trailer());
+        assert_eq!(data, save.raw_data());
+
+        save.commit().unwrap();
+        let committed = save.raw_data();
+
+        assert_eq!(data.len(), committed.len());
+        assert_eq!(&[0x5A; 16], &committed[0x20000..]);
+
+        let mut data = synthetic_save()[..0x10000].to_vec();
+        data.extend_from_slice(&[0x01, 0x02, 0x03]);
+        let save = SaveFile::parse(&data).unwrap();
+
+        assert_eq!(SaveVariant::Half, save.variant());
+        assert_eq!(Trailer::Unknown(3), save.trailer());
+        assert_eq!(&[0x01, 0x02, 0x03], save.trailer_data());
+    }
+
+    #[test]
+    fn blocks() {
+        let save = SaveFile::parse(&synthetic_save()).unwrap();
+
+        assert_eq!(
+            vec![
+                BlockInfo {
+                    block: SaveBlock::A,
+                    save_index: 10,
+                    valid: true,
+                    current: false,
+                },
+                BlockInfo {
+                    block: SaveBlock::B,
+                    save_index: 11,
+                    valid: true,
+                    current: true,
+                },
+            ],
+            save.blocks()
+        );
+    }
+
+    #[test]
+    fn view_block() {
+        let save = SaveFile::parse(&synthetic_save()).unwrap();
+        let backup = save.view_block(SaveBlock::A).unwrap();
+
+        assert_eq!(0xBB, save.ot_name().unwrap()[0]);
+        assert_eq!(0xAA, backup.ot_name().unwrap()[0]);
+        assert!(backup.blocks()[0].current);
+
+        let half_save = SaveFile::parse(&synthetic_save()[..0x10000]).unwrap();
+        assert!(matches!(
+            half_save.view_block(SaveBlock::B),
+            Err(SaveDataError::BlockNotFound(SaveBlock::B))
+        ));
+    }
+
+    #[test]
+    fn promote_backup() {
+        let data = synthetic_save();
+        let mut save = SaveFile::parse(&data).unwrap();
+
+        save.promote_backup().unwrap();
+
+        assert_eq!(0xAA, save.ot_name().unwrap()[0]);
+        assert_eq!(data[0xE000..0x1C000], save.raw_data()[0xE000..0x1C000]);
+
+        let reparsed = SaveFile::parse(&save.raw_data()).unwrap();
+        let blocks = reparsed.blocks();
+        assert!(blocks[0].current && blocks[0].valid);
+        assert_eq!(12, blocks[0].save_index);
+    }
+
+    #[test]
+    fn block_selection() {
+        let save = SaveFile::parse(&synthetic_save()).unwrap();
+        assert_eq!(BlockSelection::BNewer, save.block_selection());
+
+        let backup = save.view_block(SaveBlock::A).unwrap();
+        assert_eq!(
+            BlockSelection::Selected(SaveBlock::A),
+            backup.block_selection()
+        );
+
+        let half_save = SaveFile::parse(&synthetic_save()[..0x10000]).unwrap();
+        assert_eq!(BlockSelection::SingleBlock, half_save.block_selection());
+    }
+
+    #[test]
+    fn block_selection_erased() {
+        let mut data = synthetic_save();
+        data[0xE000..0x1C000].fill(0xFF);
+        let save = SaveFile::parse(&data).unwrap();
+        assert_eq!(BlockSelection::BEmpty, save.block_selection());
+
+        let mut data = synthetic_save();
+        data[0x0000..0xE000].fill(0);
+        let save = SaveFile::parse(&data).unwrap();
+        assert_eq!(BlockSelection::AEmpty, save.block_selection());
+    }
+
+    #[test]
+    fn block_selection_corrupt() {
+        let mut data = synthetic_save();
+        // Team / items of block B, the most recent block
+        data[0xE000 + 0x2000 + 0x10] = 1;
+        let save = SaveFile::parse(&data).unwrap();
+
+        assert_eq!(BlockSelection::BCorrupt, save.block_selection());
+        assert_eq!(0xAA, save.ot_name().unwrap()[0]);
+
+        // Team / items of block A
+        data[0x1000 + 0x10] = 1;
+        let mut save = SaveFile::parse(&data).unwrap();
+
+        assert_eq!(
+            BlockSelection::BothCorrupt(SaveBlock::B),
+            save.block_selection()
+        );
+
+        // Fixing the checksums makes the block in use valid again
+        save.update_checksums().unwrap();
+        assert_eq!(BlockSelection::ACorrupt, save.block_selection());
+        assert_eq!(0xBB, save.ot_name().unwrap()[0]);
+    }
+
+    #[test]
+    fn block_selection_wraparound() {
+        let mut data = synthetic_save();
+        write_block(&mut data, 0x0000, u32::MAX, 0);
+        write_block(&mut data, 0xE000, 0, 1);
+        let save = SaveFile::parse(&data).unwrap();
+        assert_eq!(BlockSelection::BNewer, save.block_selection());
+
+        write_block(&mut data, 0x0000, 0, 0);
+        write_block(&mut data, 0xE000, u32::MAX, 1);
+        let save = SaveFile::parse(&data).unwrap();
+        assert_eq!(BlockSelection::ANewer, save.block_selection());
+    }
+
+    #[test]
+    fn game_version_emerald() {
+        let save = SaveFile::parse(&synthetic_save()).unwrap();
+
+        assert_eq!(GameVersion::Emerald, save.game_version().unwrap());
+        assert_eq!(EMERALD_SECURITY_KEY, save.game_code().unwrap());
+    }
+
+    #[test]
+    fn game_version_fire_red_leaf_green() {
+        let mut data = vec![0; 0x10000];
+        LittleEndian::write_u32(&mut data[0x00AC..], 1);
+        LittleEndian::write_u32(&mut data[0x0F20..], 0x1234_5678);
+        LittleEndian::write_u32(&mut data[0x0AF8..], 0x1234_5678 ^ 250);
+        write_block(&mut data, 0x0000, 1, 0);
+
+        let save = SaveFile::parse(&data).unwrap();
+
+        assert_eq!(GameVersion::FireRedLeafGreen, save.game_version().unwrap());
+    }
+
+    #[test]
+    fn game_version_ruby_sapphire() {
+        let mut data = vec![0; 0x10000];
+        write_block(&mut data, 0x0000, 1, 0);
+
+        let save = SaveFile::parse(&data).unwrap();
+
+        assert_eq!(GameVersion::RubySapphire, save.game_version().unwrap());
+    }
+
+    #[test]
+    fn game_version_ruby_sapphire_garbage_code() {
+        for game_code in [1, 0xDEAD_BEEF] {
+            let mut data = vec![0; 0x10000];
+            LittleEndian::write_u32(&mut data[0x00AC..], game_code);
+            // Potion x5 in the first Items pocket slot, Ruby/Sapphire quantities are not masked
+            LittleEndian::write_u16(&mut data[SECTION_SIZE + 0x0560..], 13);
+            LittleEndian::write_u16(&mut data[SECTION_SIZE + 0x0562..], 5);
+            write_block(&mut data, 0x0000, 1, 0);
+
+            let save = SaveFile::parse(&data).unwrap();
+
+            assert_eq!(GameVersion::RubySapphire, save.game_version().unwrap());
+            assert_eq!(5, save.pocket(Pocket::Items).unwrap()[0].quantity);
+        }
+    }
+
+    /// Builds a 64 KiB FireRed/LeafGreen save with the given security key.
+    fn fire_red_save(security_key: u32) -> Vec<u8> {
+        let mut data = vec![0; 0x10000];
+        LittleEndian::write_u32(&mut data[0x00AC..], 1);
+        LittleEndian::write_u32(&mut data[0x0F20..], security_key);
+        LittleEndian::write_u32(&mut data[0x0AF8..], security_key);
+        write_block(&mut data, 0x0000, 1, 0);
+
+        data
+    }
+
+    #[test]
+    fn layout_save_block_1_field() {
+        assert_eq!(
+            Field {
+                section: SectionID::GameState,
+                offset: 0x02F0,
+                size: 300
+            },
+            layout::EMERALD.flags
+        );
+        assert_eq!(
+            SectionID::TeamItems,
+            layout::FIRE_RED_LEAF_GREEN.flags.section
+        );
+        assert_eq!(
+            SectionID::RivalInfo,
+            layout::FIRE_RED_LEAF_GREEN.rival_name.unwrap().section
+        );
+    }
+
+    #[test]
+    fn field_across_sections() {
+        let mut save = SaveFile::parse(&fire_red_save(0x1234_5678)).unwrap();
+        let flags = layout::FIRE_RED_LEAF_GREEN.flags;
+        let data: Vec<u8> = (0..flags.size).map(|i| i as u8).collect();
+
+        save.set_field(flags, &data).unwrap();
+
+        assert_eq!(data, save.field(flags).unwrap());
+        // Flags 0x0EE0 - 0x0FFF of SaveBlock1 end up in the first 0x80 bytes of Game State
+        let raw = save.raw_data();
+        assert_eq!(
+            &data[..0xA0],
+            &raw[SECTION_SIZE + 0x0EE0..SECTION_SIZE + 0x0F80]
+        );
+        assert_eq!(
+            &data[0xA0..],
+            &raw[2 * SECTION_SIZE..2 * SECTION_SIZE + 0x80]
+        );
+        assert_checksums_valid(&save);
+    }
+
+    #[test]
+    fn field_invalid() {
+        let mut save = SaveFile::parse(&fire_red_save(0x1234_5678)).unwrap();
+        let money = layout::FIRE_RED_LEAF_GREEN.money;
+
+        assert!(matches!(
+            save.set_field(money, &[0; 2]),
+            Err(SaveDataError::InvalidDataLength {
+                expected: 4,
+                found: 2
+            })
+        ));
+        assert!(matches!(
+            save.field(Field::trainer_info(0x0F00, 0x100)),
+            Err(SaveDataError::InvalidOffset(0x1000))
+        ));
+    }
+
+    #[test]
+    fn field_pc_buffer() {
+        let mut save = SaveFile::parse(&synthetic_save()).unwrap();
+        // First slot of box 1, past the current box number
+        let slot = Field {
+            section: SectionID::PCbufferA,
+            offset: 0x0004,
+            size: 80,
+        };
+
+        save.set_field(slot, &TORCHIK[..80]).unwrap();
+        assert_eq!("Torchic", save.pc_box(0).unwrap()[0].species());
+
+        // Writing through the PC buffer keeps the field
+        save.set_box_name(0, "FIELD").unwrap();
+        assert_eq!(TORCHIK[..80], save.field(slot).unwrap()[..]);
+        assert_eq!("Torchic", save.pc_box(0).unwrap()[0].species());
+        assert_checksums_valid(&save);
+    }
+
+    #[test]
+    fn pocket_fire_red_security_key() {
+        let mut save = SaveFile::parse(&fire_red_save(0x1234_5678)).unwrap();
+        let items = layout::FIRE_RED_LEAF_GREEN.items;
+        let mut data = vec![0; items.size];
+        LittleEndian::write_u16(&mut data[0..], 13);
+        LittleEndian::write_u16(&mut data[2..], 5 ^ 0x5678);
+        save.set_field(items, &data).unwrap();
+
+        assert_eq!(5, save.pocket(Pocket::Items).unwrap()[0].quantity);
+    }
+
+    #[test]
+    fn pocket_pc_items() {
+        let mut save = SaveFile::parse(&fire_red_save(0x1234_5678)).unwrap();
+
+        assert_eq!(30, save.pocket(Pocket::PcItems).unwrap().len());
+
+        save.save_pocket(Pocket::PcItems, &[Item::new(13, 5)])
+            .unwrap();
+
+        // PC items at 0x0298 of Team / items, quantities are not masked
+        let raw = save.raw_data();
+        assert_eq!([13, 0, 5, 0], raw[0x1000 + 0x0298..0x1000 + 0x029C]);
+        assert_eq!(Item::new(13, 5), save.pocket(Pocket::PcItems).unwrap()[0]);
+
+        let save = SaveFile::parse(&synthetic_save()).unwrap();
+        assert_eq!(50, save.pocket(Pocket::PcItems).unwrap().len());
+    }
+
+    #[test]
+    fn pocket_items() {
+        let mut save = SaveFile::parse(&synthetic_save()).unwrap();
+        let potion = Item::from_name("Potion", 5).unwrap();
+
+        assert_eq!(Item::new(13, 5), potion);
+        assert_eq!("Potion", potion.name());
+
+        // Items missing from the database keep their index
+        save.save_pocket(Pocket::Items, &[potion, Item::new(0x0300, 2)])
+            .unwrap();
+        let items = save.pocket(Pocket::Items).unwrap();
+
+        assert_eq!(30, items.len());
+        assert_eq!(vec![potion, Item::new(0x0300, 2)], items[..2]);
+        assert_eq!("Unknown", items[1].name());
+        assert!(items[2..]
+            .iter()
+            .all(|item| item.is_empty() && item.quantity == 0));
+
+        // Empty slots are masked with the security key too
+        assert_eq!(
+            [0, 0, 0x4E, 0x3D],
+            save.raw_data()[0x10000 + 0x0560 + 8..0x10000 + 0x0560 + 12]
+        );
+
+        assert!(matches!(
+            save.save_pocket(Pocket::Items, &[Item::new(13, 100)]),
+            Err(SaveDataError::InvalidPocket { .. })
+        ));
+        assert!(matches!(
+            Item::from_name("Missingno", 1),
+            Err(SaveDataError::UnknownItem(_))
+        ));
+
+        let mut save = SaveFile::parse(&fire_red_save(0x1234_5678)).unwrap();
+        save.save_pocket(Pocket::Items, &[Item::new(13, 999)])
+            .unwrap();
+        assert_eq!(999, save.pocket(Pocket::Items).unwrap()[0].quantity);
+    }
+
+    /// Writes the slots of a pocket at `offset` the way the game does, masking the quantities of
+    /// the empty slots as well.
+    fn write_pocket_slots(data: &mut [u8], offset: usize, slots: usize, items: &[Item], key: u16) {
+        for slot in 0..slots {
+            let item = items.get(slot).copied().unwrap_or_default();
+            let start = offset + slot * 4;
+
+            LittleEndian::write_u16(&mut data[start..], item.id);
+            LittleEndian::write_u16(&mut data[start + 2..], item.quantity ^ key);
+        }
+    }
+
+    #[test]
+    fn pocket_berries() {
+        let mut data = synthetic_save();
+        // Cheri Berry stack of 150, above the limit of the other Emerald bag pockets
+        write_pocket_slots(&mut data, 0x10790, 46, &[Item::new(133, 150)], 0x3D4E);
+        write_block(&mut data, 0xE000, 11, 1);
+
+        let mut save = SaveFile::parse(&data).unwrap();
+        let items = save.pocket(Pocket::Berries).unwrap();
+
+        assert_eq!(Item::new(133, 150), items[0]);
+
+        save.save_pocket(Pocket::Berries, &items).unwrap();
+        assert_eq!(data[..], save.raw_data()[..]);
+        assert_checksums_valid(&save);
+    }
+
+    #[test]
+    fn pocket_written_by_game() {
+        let mut data = synthetic_save();
+        let key = 0x3D4E;
+        // More Potions bought once the first stack is full
+        let items = [Item::new(13, 99), Item::new(14, 3), Item::new(13, 50)];
+        write_pocket_slots(&mut data, 0x10560, 30, &items, key);
+        write_pocket_slots(&mut data, 0x105D8, 30, &[Item::new(259, 1)], key);
+        write_pocket_slots(&mut data, 0x10650, 16, &[Item::new(4, 99)], key);
+        write_pocket_slots(&mut data, 0x10690, 64, &[Item::new(289, 1)], key);
+        write_pocket_slots(&mut data, 0x10790, 46, &[Item::new(133, 999)], key);
+        let pc_items = [Item::new(13, 999), Item::new(13, 999), Item::new(259, 1)];
+        write_pocket_slots(&mut data, 0x10498, 50, &pc_items, 0);
+        write_block(&mut data, 0xE000, 11, 1);
+
+        let mut save = SaveFile::parse(&data).unwrap();
+
+        for pocket in [
+            Pocket::Items,
+            Pocket::Key,
+            Pocket::Pokeballs,
+            Pocket::Tms,
+            Pocket::Berries,
+            Pocket::PcItems,
+        ] {
+            let items = save.pocket(pocket).unwrap();
+
+            assert!(save.validate_pocket(pocket, &items).unwrap().is_empty());
+            save.save_pocket(pocket, &items).unwrap();
+        }
+        assert_eq!(data[..], save.raw_data()[..]);
+
+        // Full stacks are left alone when fixing
+        for pocket in [Pocket::Berries, Pocket::PcItems] {
+            let items = save.pocket(pocket).unwrap();
+            save.save_pocket_fixed(pocket, &items).unwrap();
+        }
+        assert_eq!(data[..], save.raw_data()[..]);
+    }
+
+    #[test]
+    fn pocket_key_items() {
+        let mut save = SaveFile::parse(&synthetic_save()).unwrap();
+        let root_fossil = Item::new(286, 1);
+        let claw_fossil = Item::new(287, 1);
+
+        save.save_pocket(Pocket::Key, &[root_fossil]).unwrap();
+        save.save_pocket_fixed(Pocket::Key, &[root_fossil, claw_fossil])
+            .unwrap();
+
+        assert_eq!(
+            vec![root_fossil, claw_fossil],
+            save.pocket(Pocket::Key).unwrap()[..2]
+        );
+        assert!(save.pocket(Pocket::Items).unwrap()[0].is_empty());
+
+        // Key items of FireRed/LeafGreen, Bicycle and Tea
+        let mut save = SaveFile::parse(&fire_red_save(0x1234_5678)).unwrap();
+        let items = [Item::new(360, 1), Item::new(369, 1)];
+
+        save.save_pocket(Pocket::Key, &items).unwrap();
+        assert_eq!(items, save.pocket(Pocket::Key).unwrap()[..2]);
+        assert!(matches!(
+            save.save_pocket(Pocket::Items, &items),
+            Err(SaveDataError::InvalidPocket { .. })
+        ));
+    }
+
+    #[test]
+    fn hall_of_fame_never_written() {
+        let save = SaveFile::parse(&synthetic_save()).unwrap();
+
+        assert!(save.hall_of_fame().unwrap().is_empty());
+        assert!(matches!(
+            SaveFile::parse(&synthetic_save()[..0x10000])
+                .unwrap()
+                .hall_of_fame(),
+            Err(SaveDataError::HallOfFameNotFound)
+        ));
+    }
+
+    #[test]
+    fn hall_of_fame_round_trip() {
+        let mut save = SaveFile::parse(&synthetic_save()).unwrap();
+        let torchik = HallOfFameMon::from(&Pokemon::new(0, &TORCHIK));
+        let teams = vec![
+            HallOfFameTeam {
+                members: vec![torchik],
+            },
+            HallOfFameTeam {
+                members: vec![torchik; 6],
+            },
+        ];
+
+        save.set_hall_of_fame(&teams).unwrap();
+        let hall_of_fame = save.hall_of_fame().unwrap();
+
+        assert_eq!(teams, hall_of_fame);
+        assert_eq!("Torchic", hall_of_fame[1].members[5].species());
+        assert_eq!(5, hall_of_fame[1].members[5].level());
+        assert_eq!(
+            0x08012025,
+            LittleEndian::read_u32(&save.raw_data()[0x1DFF8..])
+        );
+
+        save.clear_hall_of_fame().unwrap();
+
+        assert!(save.hall_of_fame().unwrap().is_empty());
+    }
+
+    #[test]
+    fn hall_of_fame_edit() {
+        let mut save = SaveFile::parse(&synthetic_save()).unwrap();
+        let mut mon = HallOfFameMon::default();
+
+        mon.set_species("Rayquaza").unwrap();
+        mon.set_nickname("RAYQUAZA").unwrap();
+        mon.set_personality_value(0x12345678);
+        mon.set_ot_id(TrainerID::from(0x0001D431));
+        mon.set_level(70);
+
+        assert!(matches!(
+            mon.set_species("Missingno"),
+            Err(SaveDataError::UnknownSpecies(_))
+        ));
+        assert!(matches!(
+            mon.set_nickname("RAYQUAZAAAAA"),
+            Err(SaveDataError::InvalidDataLength {
+                expected: 10,
+                found: 12
+            })
+        ));
+        assert!(matches!(
+            mon.set_nickname("RAY\u{1F409}"),
+            Err(SaveDataError::CharacterSet(_))
+        ));
+
+        save.set_hall_of_fame(&[HallOfFameTeam { members: vec![mon] }])
+            .unwrap();
+        let stored = save.hall_of_fame().unwrap()[0].members[0];
+
+        assert_eq!(mon, stored);
+        assert_eq!("Rayquaza", stored.species());
+        assert_eq!(384, stored.nat_dex_number());
+        assert_eq!("RAYQUAZA", stored.nickname());
+        assert_eq!(0x12345678, stored.personality_value());
+        assert_eq!(0x0001D431, u32::from(stored.ot_id()));
+        assert_eq!(70, stored.level());
+    }
+
+    #[test]
+    fn hall_of_fame_invalid() {
+        let mut save = SaveFile::parse(&synthetic_save()).unwrap();
+        let team = HallOfFameTeam {
+            members: vec![HallOfFameMon::from(&Pokemon::new(0, &TORCHIK))],
+        };
+
+        assert!(matches!(
+            save.set_hall_of_fame(&vec![team.clone(); 51]),
+            Err(SaveDataError::InvalidDataLength {
+                expected: 50,
+                found: 51
+            })
+        ));
+
+        save.set_hall_of_fame(&[team]).unwrap();
+        let mut data = save.raw_data();
+        data[0x1C004] ^= 0xFF;
+
+        assert!(matches!(
+            SaveFile::parse(&data).unwrap().hall_of_fame(),
+            Err(SaveDataError::ChecksumMismatch { .. })
+        ));
+
+        data[0x1C004] ^= 0xFF;
+        data[0x1DFF8] = 0;
+
+        assert!(matches!(
+            SaveFile::parse(&data).unwrap().hall_of_fame(),
+            Err(SaveDataError::InvalidSignature {
+                offset: 0x1D000,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn trainer_profile() {
+        let mut save = SaveFile::parse(&synthetic_save()).unwrap();
+        let play_time = PlayTime {
+            hours: 12,
+            minutes: 34,
+            seconds: 56,
+            frames: 7,
+        };
+
+        save.set_trainer_name("MAY").unwrap();
+        save.set_trainer_gender(TrainerGender::Female).unwrap();
+        save.set_trainer_id(TrainerID::from([0x39, 0x30, 0x31, 0xD4]))
+            .unwrap();
+        save.set_play_time(play_time).unwrap();
+        save.set_money(123456).unwrap();
+        save.set_coins(9999).unwrap();
+        let trainer = save.trainer().unwrap();
+
+        assert_eq!("MAY", trainer.name);
+        assert_eq!(TrainerGender::Female, trainer.gender);
+        assert_eq!(TrainerID::new(12345, 54321), save.ot_id().unwrap());
+        assert_eq!(play_time, trainer.play_time);
+        assert_eq!(123456, trainer.money);
+        assert_eq!(9999, trainer.coins);
+        assert_checksums_valid(&save);
+
+        // Team / items of block B, stored second because of the rotation
+        let raw = save.raw_data();
+        assert_eq!(
+            123456 ^ EMERALD_SECURITY_KEY,
+            LittleEndian::read_u32(&raw[0x10490..])
+        );
+        assert_eq!(
+            9999 ^ EMERALD_SECURITY_KEY as u16,
+            LittleEndian::read_u16(&raw[0x10494..])
+        );
+    }
+
+    #[test]
+    fn trainer_profile_invalid() {
+        let mut save = SaveFile::parse(&synthetic_save()).unwrap();
+
+        assert!(matches!(
+            save.set_money(1_000_000),
+            Err(SaveDataError::ValueOutOfRange {
+                value: 1_000_000,
+                max: 999999
+            })
+        ));
+        assert!(matches!(
+            save.set_coins(10000),
+            Err(SaveDataError::ValueOutOfRange { .. })
+        ));
+        assert!(matches!(
+            save.set_play_time(PlayTime {
+                minutes: 60,
+                ..Default::default()
+            }),
+            Err(SaveDataError::ValueOutOfRange { value: 60, max: 59 })
+        ));
+        save.set_trainer_name("BRENDAN").unwrap();
+        assert!(matches!(
+            save.set_trainer_name("BRENDANO"),
+            Err(SaveDataError::InvalidDataLength {
+                expected: 7,
+                found: 8
+            })
+        ));
+        assert!(matches!(
+            save.set_trainer_name("€"),
+            Err(SaveDataError::CharacterSet(_))
+        ));
+    }
+
+    #[test]
+    fn trainer_id() {
+        let trainer_id = TrainerID::from([0x39, 0x30, 0x31, 0xD4]);
+
+        assert_eq!(12345, trainer_id.public());
+        assert_eq!(54321, trainer_id.secret());
+        assert_eq!("12345/54321", trainer_id.to_string());
+        assert_eq!("00042/00007", TrainerID::new(42, 7).to_string());
+        assert_eq!([0x39, 0x30, 0x31, 0xD4], <[u8; 4]>::from(trainer_id));
+        assert_eq!(vec![0x39, 0x30, 0x31, 0xD4], Vec::<u8>::from(trainer_id));
+        assert_eq!(trainer_id, TrainerID::from(u32::from(trainer_id)));
+    }
+
+    #[test]
+    fn shiny_value() {
+        let trainer_id = TrainerID::new(12345, 54321);
+        let torchik = Pokemon::new(0, &TORCHIK);
+
+        assert_eq!(0, trainer_id.shiny_value(0xD431_3039));
+        assert!(trainer_id.is_shiny(0xD431_3039 ^ 7));
+        assert!(!trainer_id.is_shiny(0xD431_3039 ^ 8));
+        assert!(!torchik.is_shiny());
+    }
+
+    #[test]
+    fn event_flags() {
+        let mut save = SaveFile::parse(&synthetic_save()).unwrap();
+
+        assert!(!save.flag(emerald::BADGES[0]).unwrap());
+
+        save.set_flag(emerald::BADGES[0], true).unwrap();
+        save.set_flag(emerald::ENABLE_SHIP_NAVEL_ROCK, true)
+            .unwrap();
+
+        assert!(save.flag(emerald::BADGES[0]).unwrap());
+        assert!(!save.flag(emerald::BADGES[1]).unwrap());
+        assert!(save.flag(emerald::ENABLE_SHIP_NAVEL_ROCK).unwrap());
+        // Byte 0x10C of the flags at 0x02F0 of Game State, stored third in block B
+        assert_eq!(0x80, save.raw_data()[0x11000 + 0x02F0 + 0x010C]);
+        assert_checksums_valid(&save);
+
+        save.set_flag(emerald::BADGES[0], false).unwrap();
+
+        assert!(!save.flag(emerald::BADGES[0]).unwrap());
+        assert_eq!(2400, save.flags().unwrap().len());
+        assert!(matches!(
+            save.set_flag(2400, true),
+            Err(SaveDataError::ValueOutOfRange {
+                value: 2400,
+                max: 2399
+            })
+        ));
+    }
+
+    #[test]
+    fn event_flags_across_sections() {
+        let mut save = SaveFile::parse(&fire_red_save(0x1234_5678)).unwrap();
+        let version = save.game_version().unwrap();
+        let mut flags = save.flags().unwrap();
+
+        for badge in flags::badge_flags(version) {
+            flags.set(badge, true).unwrap();
+        }
+        flags
+            .set(fire_red_leaf_green::SYS_NATIONAL_DEX, true)
+            .unwrap();
+        save.save_flags(&flags).unwrap();
+
+        assert_eq!(flags, save.flags().unwrap());
+        assert!(save.flag(flags::national_dex_flag(version)).unwrap());
+        // Badges are past the end of Team / items, at 0x0064 of Game State
+        assert_eq!(0xFF, save.raw_data()[0x2000 + 0x0064]);
+        assert_checksums_valid(&save);
+    }
+
+    #[test]
+    fn event_flags_legendaries_and_tickets() {
+        let mut ruby_sapphire_data = vec![0; 0x10000];
+        write_block(&mut ruby_sapphire_data, 0x0000, 1, 0);
+
+        let saves = [
+            (
+                ruby_sapphire_data,
+                vec![
+                    ruby_sapphire::RECEIVED_SS_TICKET,
+                    ruby_sapphire::SYS_HAS_EON_TICKET,
+                    ruby_sapphire::CAUGHT_KYOGRE,
+                    ruby_sapphire::CAUGHT_GROUDON,
+                    ruby_sapphire::CAUGHT_RAYQUAZA,
+                    ruby_sapphire::CAUGHT_REGIROCK,
+                    ruby_sapphire::CAUGHT_REGICE,
+                    ruby_sapphire::CAUGHT_REGISTEEL,
+                    ruby_sapphire::CAUGHT_LATIAS_OR_LATIOS,
+                    ruby_sapphire::HIDE_KYOGRE,
+                    ruby_sapphire::HIDE_GROUDON,
+                    ruby_sapphire::HIDE_RAYQUAZA,
+                    ruby_sapphire::HIDE_REGIROCK,
+                    ruby_sapphire::HIDE_REGICE,
+                    ruby_sapphire::HIDE_REGISTEEL,
+                    ruby_sapphire::HIDE_LATIAS_OR_LATIOS,
+                ],
+            ),
+            (
+                synthetic_save(),
+                vec![
+                    emerald::RECEIVED_SS_TICKET,
+                    emerald::ENABLE_SHIP_SOUTHERN_ISLAND,
+                    emerald::ENABLE_SHIP_BIRTH_ISLAND,
+                    emerald::ENABLE_SHIP_FARAWAY_ISLAND,
+                    emerald::ENABLE_SHIP_NAVEL_ROCK,
+                    emerald::CAUGHT_KYOGRE,
+                    emerald::CAUGHT_GROUDON,
+                    emerald::CAUGHT_RAYQUAZA,
+                    emerald::CAUGHT_REGIROCK,
+                    emerald::CAUGHT_REGICE,
+                    emerald::CAUGHT_REGISTEEL,
+                    emerald::CAUGHT_LATIAS_OR_LATIOS,
+                    emerald::CAUGHT_LUGIA,
+                    emerald::CAUGHT_HO_OH,
+                    emerald::CAUGHT_MEW,
+                    emerald::CAUGHT_DEOXYS,
+                    emerald::HIDE_KYOGRE,
+                    emerald::HIDE_GROUDON,
+                    emerald::HIDE_RAYQUAZA,
+                    emerald::HIDE_REGIROCK,
+                    emerald::HIDE_REGICE,
+                    emerald::HIDE_REGISTEEL,
+                    emerald::HIDE_LATIAS_OR_LATIOS,
+                    emerald::HIDE_LUGIA,
+                    emerald::HIDE_HO_OH,
+                    emerald::HIDE_MEW,
+                    emerald::HIDE_DEOXYS,
+                ],
+            ),
+            (
+                fire_red_save(0x1234_5678),
+                vec![
+                    fire_red_leaf_green::GOT_SS_TICKET,
+                    fire_red_leaf_green::ENABLE_SHIP_BIRTH_ISLAND,
+                    fire_red_leaf_green::ENABLE_SHIP_NAVEL_ROCK,
+                    fire_red_leaf_green::CAUGHT_ARTICUNO,
+                    fire_red_leaf_green::CAUGHT_ZAPDOS,
+                    fire_red_leaf_green::CAUGHT_MOLTRES,
+                    fire_red_leaf_green::CAUGHT_MEWTWO,
+                    fire_red_leaf_green::CAUGHT_LUGIA,
+                    fire_red_leaf_green::CAUGHT_HO_OH,
+                    fire_red_leaf_green::CAUGHT_DEOXYS,
+                    fire_red_leaf_green::HIDE_ARTICUNO,
+                    fire_red_leaf_green::HIDE_ZAPDOS,
+                    fire_red_leaf_green::HIDE_MOLTRES,
+                    fire_red_leaf_green::HIDE_MEWTWO,
+                    fire_red_leaf_green::HIDE_LUGIA,
+                    fire_red_leaf_green::HIDE_HO_OH,
+                    fire_red_leaf_green::HIDE_DEOXYS,
+                ],
+            ),
+        ];
+
+        for (data, event_flags) in saves {
+            let mut save = SaveFile::parse(&data).unwrap();
+            let mut flags = save.flags().unwrap();
+
+            for flag in &event_flags {
+                assert!(!flags.get(*flag).unwrap());
+                flags.set(*flag, true).unwrap();
+            }
+            save.save_flags(&flags).unwrap();
+
+            let flags = save.flags().unwrap();
+            let badge = flags::badge_flags(save.game_version().unwrap())[0];
+
+            for flag in &event_flags {
+                assert!(flags.get(*flag).unwrap());
+            }
+            assert!(!flags.get(badge).unwrap());
+            assert_checksums_valid(&save);
+        }
+    }
+
+    #[test]
+    fn game_vars() {
+        let mut save = SaveFile::parse(&synthetic_save()).unwrap();
+
+        save.set_var(EmeraldVar::AshGatherCount, 1234).unwrap();
+        save.set_var(0x40FF_u16, 0xBEEF).unwrap();
+
+        assert_eq!(1234, save.var(EmeraldVar::AshGatherCount).unwrap());
+        assert_eq!(0xBEEF, save.var(0x40FF_u16).unwrap());
+        assert_eq!(0, save.var(EmeraldVar::StarterMon).unwrap());
+        assert_eq!(0x4044, u16::from(RubySapphireVar::TrickHouseLevel));
+        // Word 0x48 of the vars at 0x041C of Game State, stored third in block B
+        assert_eq!(
+            1234,
+            LittleEndian::read_u16(&save.raw_data()[0x11000 + 0x041C + 0x0090..])
+        );
+        assert_checksums_valid(&save);
+        assert_eq!(256, save.vars().unwrap().len());
+        assert!(matches!(
+            save.set_var(0x4100_u16, 1),
+            Err(SaveDataError::ValueOutOfRange {
+                value: 0x4100,
+                max: 0x40FF
+            })
+        ));
+        assert!(save.var(0x3FFF_u16).is_err());
+    }
+
+    #[test]
+    fn game_vars_fire_red() {
+        let mut save = SaveFile::parse(&fire_red_save(0x1234_5678)).unwrap();
+        let mut vars = save.vars().unwrap();
+
+        vars.set(FireRedLeafGreenVar::NationalDex, 0x6258).unwrap();
+        vars.set(FireRedLeafGreenVar::StarterMon, 2).unwrap();
+        save.save_vars(&vars).unwrap();
+
+        assert_eq!(2, save.var(FireRedLeafGreenVar::StarterMon).unwrap());
+
+        assert_eq!(0x6258, save.var(FireRedLeafGreenVar::NationalDex).unwrap());
+        // FireRed/LeafGreen vars start at 0x0080 of Game State
+        assert_eq!(
+            0x6258,
+            LittleEndian::read_u16(&save.raw_data()[0x2000 + 0x0080 + 0x009C..])
+        );
+    }
+
+    #[test]
+    fn pokedex() {
+        let mut save = SaveFile::parse(&synthetic_save()).unwrap();
+        let mut pokedex = save.pokedex().unwrap();
+
+        pokedex.set_owned(255, true).unwrap();
+        pokedex.set_seen(386, true).unwrap();
+        save.save_pokedex(&pokedex).unwrap();
+        let pokedex = save.pokedex().unwrap();
+
+        assert!(pokedex.is_owned(255).unwrap());
+        assert!(pokedex.is_seen(255).unwrap());
+        assert!(!pokedex.is_owned(386).unwrap());
+        assert!(pokedex.is_seen(386).unwrap());
+        assert_eq!(2, pokedex.seen_count());
+        assert_eq!(1, pokedex.owned_count());
+        assert!(save.is_pokedex_consistent().unwrap());
+        assert_checksums_valid(&save);
+
+        // Seen copies at 0x0988 of Team / items and 0x0CA4 of Rival info, block B
+        let raw = save.raw_data();
+        assert_eq!(0x40, raw[0xF000 + 0x005C + 31]);
+        assert_eq!(0x40, raw[0x10000 + 0x0988 + 31]);
+        assert_eq!(0x40, raw[0x13000 + 0x0CA4 + 31]);
+        assert!(matches!(
+            pokedex.is_seen(387),
+            Err(SaveDataError::ValueOutOfRange {
+                value: 387,
+                max: 386
+            })
+        ));
+        assert!(pokedex.is_seen(0).is_err());
+    }
+
+    #[test]
+    fn pokedex_complete() {
+        let mut save = SaveFile::parse(&fire_red_save(0x1234_5678)).unwrap();
+        let mut pokedex = save.pokedex().unwrap();
+
+        pokedex.complete();
+        pokedex.set_seen(151, false).unwrap();
+        save.save_pokedex(&pokedex).unwrap();
+        let pokedex = save.pokedex().unwrap();
+
+        assert_eq!(NATIONAL_DEX_COUNT as usize - 1, pokedex.seen_count());
+        assert_eq!(NATIONAL_DEX_COUNT as usize - 1, pokedex.owned_count());
+        assert!(!pokedex.is_owned(151).unwrap());
+        assert!(save.is_pokedex_consistent().unwrap());
+
+        let mut data = save.raw_data();
+        data[0x1000 + 0x05F8] ^= 0x01;
+        write_block(&mut data, 0x0000, 1, 0);
+
+        assert!(!SaveFile::parse(&data)
+            .unwrap()
+            .is_pokedex_consistent()
+            .unwrap());
+    }
+
+    #[test]
+    fn unlock_national_dex() {
+        let mut save = SaveFile::parse(&synthetic_save()).unwrap();
+
+        assert!(!save.is_national_dex_unlocked().unwrap());
+
+        save.unlock_national_dex().unwrap();
+
+        assert!(save.is_national_dex_unlocked().unwrap());
+        assert_eq!(0xDA, save.raw_data()[0xF000 + 0x001A]);
+        assert!(save.flag(emerald::SYS_NATIONAL_DEX).unwrap());
+        assert_eq!(0x0302, save.var(EmeraldVar::NationalDex).unwrap());
+
+        let mut save = SaveFile::parse(&fire_red_save(0x1234_5678)).unwrap();
+        save.unlock_national_dex().unwrap();
+
+        assert!(save.is_national_dex_unlocked().unwrap());
+        assert_eq!(0xB9, save.raw_data()[0x001B]);
+        assert!(save.flag(fire_red_leaf_green::SYS_NATIONAL_DEX).unwrap());
+        assert_eq!(0x6258, save.var(FireRedLeafGreenVar::NationalDex).unwrap());
+        assert_checksums_valid(&save);
+    }
+
+    #[test]
+    fn pokedex_personality_values() {
+        let mut save = SaveFile::parse(&synthetic_save()).unwrap();
+        let mut pokedex = save.pokedex().unwrap();
+        let mut pokemon = Pokemon::new(0, &TORCHIK);
+
+        assert!(!pokedex.sync_personality_value(&pokemon));
+
+        pokemon.set_species("Spinda").unwrap();
+        assert!(pokedex.sync_personality_value(&pokemon));
+        pokedex.set_unown_personality_value(0xDEAD_BEEF);
+        save.save_pokedex(&pokedex).unwrap();
+        let pokedex = save.pokedex().unwrap();
+
+        assert_eq!(
+            pokemon.personality_value(),
+            pokedex.spinda_personality_value()
+        );
+        assert_eq!(0xDEAD_BEEF, pokedex.unown_personality_value());
+        assert_eq!(
+            pokemon.personality_value().to_le_bytes(),
+            save.raw_data()[0xF000 + 0x0020..0xF000 + 0x0024]
+        );
+        assert_checksums_valid(&save);
+    }
+
+    #[test]
+    fn party_members() {
+        let mut save = SaveFile::parse(&synthetic_save()).unwrap();
+        let torchik = Pokemon::new(0, &TORCHIK);
+        let mut spinda = torchik;
+        spinda.set_species("Spinda").unwrap();
+
+        assert_eq!(0, save.party_len().unwrap());
+        assert_eq!(0, save.add_to_party(torchik).unwrap());
+        assert_eq!(1, save.add_to_party(spinda).unwrap());
+        assert_eq!(2, save.party_len().unwrap());
+
+        // Party count at 0x0234 and party at 0x0238 of Team / items, block B
+        let raw = save.raw_data();
+        assert_eq!([2, 0, 0, 0], raw[0x10234..0x10238]);
+        assert_eq!(TORCHIK[..0x50], raw[0x10238..0x10238 + 0x50]);
+        assert_eq!(torchik.level(), raw[0x10238 + 0x54]);
+
+        save.swap_party(0, 1).unwrap();
+        let party = save.get_party().unwrap();
+        assert_eq!("Spinda", party[0].species());
+        assert_eq!("Torchic", party[1].species());
+
+        let removed = save.remove_from_party(0).unwrap();
+        assert_eq!("Spinda", removed.species());
+        assert_eq!(None, removed.slot());
+        assert_eq!(1, save.party_len().unwrap());
+        assert_eq!(1, save.get_party().unwrap().len());
+        assert_eq!("Torchic", save.get_party().unwrap()[0].species());
+
+        assert!(matches!(
+            save.remove_from_party(1),
+            Err(SaveDataError::InvalidPartySlot(1))
+        ));
+        assert!(matches!(
+            save.swap_party(0, 3),
+            Err(SaveDataError::InvalidPartySlot(3))
+        ));
+
+        for _ in 0..5 {
+            save.add_to_party(torchik).unwrap();
+        }
+        assert!(matches!(
+            save.add_to_party(torchik),
+            Err(SaveDataError::PartyFull)
+        ));
+        assert_checksums_valid(&save);
+    }
+
+    #[test]
+    fn partial_party() {
+        let mut data = synthetic_save();
+        // Two members, the third slot still holding a Pokémon past the count
+        LittleEndian::write_u32(&mut data[0x10234..], 2);
+        for slot in 0..3 {
+            let start = 0x10238 + slot * 100;
+            data[start..start + 100].copy_from_slice(&TORCHIK);
+        }
+        write_block(&mut data, 0xE000, 11, 1);
+
+        let save = SaveFile::parse(&data).unwrap();
+        let party = save.get_party().unwrap();
+
+        assert_eq!(2, party.len());
+        assert_eq!(Some(SlotRef::Party(1)), party[1].slot());
+        assert_eq!("Torchic", party[1].species());
+    }
+
+    #[test]
+    fn move_pokemon() {
+        let mut save = SaveFile::parse(&synthetic_save()).unwrap();
+        let torchik = Pokemon::new(0, &TORCHIK);
+        let mut spinda = torchik;
+        spinda.set_species("Spinda").unwrap();
+        save.add_to_party(torchik).unwrap();
+        save.add_to_party(spinda).unwrap();
+
+        // Party member into an empty box slot, the members after it move up
+        save.move_pokemon(SlotRef::Party(0), SlotRef::Box(3, 7))
+            .unwrap();
+        assert_eq!(1, save.party_len().unwrap());
+        assert_eq!("Spinda", save.get_party().unwrap()[0].species());
+        assert_eq!("Torchic", save.pc_box(3).unwrap()[7].species());
+
+        // The last member stays in the party
+        assert!(matches!(
+            save.move_pokemon(SlotRef::Party(0), SlotRef::Box(0, 0)),
+            Err(SaveDataError::LastPartyMember)
+        ));
+        assert_eq!(1, save.party_len().unwrap());
+        assert!(save.pc_box(0).unwrap()[0].is_empty());
+
+        // Between boxes
+        save.move_pokemon(SlotRef::Box(3, 7), SlotRef::Box(13, 29))
+            .unwrap();
+        assert!(save.pc_box(3).unwrap()[7].is_empty());
+        assert_eq!("Torchic", save.pc_box(13).unwrap()[29].species());
+
+        // Box to the party, swapping with the member already there
+        save.move_pokemon(SlotRef::Box(13, 29), SlotRef::Party(0))
+            .unwrap();
+        let party = save.get_party().unwrap();
+        assert_eq!(1, save.party_len().unwrap());
+        assert_eq!("Torchic", party[0].species());
+        assert_eq!(
+            party[0].stats().hp(party[0].level()),
+            party[0].raw_data()[0x58] as u16
+        );
+        assert_eq!("Spinda", save.pc_box(13).unwrap()[29].species());
+
+        // Box to an empty party slot, added after the last member
+        save.move_pokemon(SlotRef::Box(13, 29), SlotRef::Party(5))
+            .unwrap();
+        assert_eq!(2, save.party_len().unwrap());
+        assert_eq!("Spinda", save.get_party().unwrap()[1].species());
+        assert!(save.pc_box(13).unwrap()[29].is_empty());
+
+        assert!(matches!(
+            save.move_pokemon(SlotRef::Box(0, 0), SlotRef::Party(0)),
+            Err(SaveDataError::EmptySlot(SlotRef::Box(0, 0)))
+        ));
+        assert!(matches!(
+            save.move_pokemon(SlotRef::Party(0), SlotRef::Box(14, 0)),
+            Err(SaveDataError::InvalidBox(14))
+        ));
+        assert!(matches!(
+            save.move_pokemon(SlotRef::Party(0), SlotRef::Box(0, 30)),
+            Err(SaveDataError::InvalidBoxSlot(30))
+        ));
+        assert_checksums_valid(&save);
+    }
+
+    #[test]
+    fn daycare() {
+        let mut data = synthetic_save();
+        // Second Day Care slot at 0x30BC of SaveBlock1, in Rival info at 0x13000, block B
+        data[0x1323C..0x1323C + 80].copy_from_slice(&TORCHIK[..80]);
+        write_block(&mut data, 0xE000, 11, 1);
+
+        let mut save = SaveFile::parse(&data).unwrap();
+        let daycare = save.daycare().unwrap();
+
+        assert!(daycare[0].is_empty());
+        assert_eq!("Torchic", daycare[1].species());
+        assert_eq!(Some(SlotRef::Daycare(1)), daycare[1].slot());
+
+        save.move_pokemon(SlotRef::Daycare(1), SlotRef::Box(0, 0))
+            .unwrap();
+        assert!(save.daycare().unwrap()[1].is_empty());
+        assert_eq!("Torchic", save.pc_box(0).unwrap()[0].species());
+
+        let mut pokemon = save.pc_box(0).unwrap()[0];
+        pokemon.set_friendship(42);
+        pokemon.update_checksum();
+        save.save_pokemon(SlotRef::Daycare(0), pokemon).unwrap();
+
+        assert_eq!(42, save.daycare().unwrap()[0].friendship());
+        assert_eq!(
+            save.pc_box(0).unwrap()[0].raw_data()[..0x1C],
+            save.raw_data()[0x131B0..0x131B0 + 0x1C]
+        );
+        assert!(matches!(
+            save.save_pokemon(SlotRef::Daycare(2), pokemon),
+            Err(SaveDataError::InvalidDaycareSlot(2))
+        ));
+        assert_checksums_valid(&save);
+    }
+
+    #[test]
+    fn save_pokemon_slots() {
+        let mut save = SaveFile::parse(&synthetic_save()).unwrap();
+        save.add_to_party(Pokemon::new(0, &TORCHIK)).unwrap();
+
+        let mut pokemon = save.get_party().unwrap()[0];
+        assert_eq!(Some(SlotRef::Party(0)), pokemon.slot());
+        assert_eq!(Some(SlotRef::Box(2, 5)), save.pc_box(2).unwrap()[5].slot());
+
+        pokemon.set_friendship(42);
+        pokemon.update_checksum();
+        save.save_pokemon(pokemon.slot().unwrap(), pokemon).unwrap();
+        save.save_pokemon(SlotRef::Box(2, 5), pokemon).unwrap();
+
+        assert_eq!(42, save.get_party().unwrap()[0].friendship());
+        assert_eq!(42, save.pc_box(2).unwrap()[5].friendship());
+        assert!(matches!(
+            save.save_pokemon(SlotRef::Party(1), pokemon),
+            Err(SaveDataError::InvalidPartySlot(1))
+        ));
+        assert_checksums_valid(&save);
+    }
+
+    #[test]
+    fn pc_boxes() {
+        let mut save = SaveFile::parse(&synthetic_save()).unwrap();
+
+        save.set_current_box(13).unwrap();
+        save.set_box_name(13, "RARE").unwrap();
+        save.set_box_wallpaper(13, Wallpaper::PokeCenter).unwrap();
+        save.set_box_wallpaper(0, Wallpaper::Friends).unwrap();
+
+        assert_eq!(13, save.current_box().unwrap());
+        assert_eq!("RARE", save.box_name(13).unwrap());
+        assert_eq!(Wallpaper::PokeCenter, save.box_wallpaper(13).unwrap());
+        assert_eq!(Wallpaper::Friends, save.box_wallpaper(0).unwrap());
+
+        // PC buffer A at 0x14000 and PC buffer I at 0xE000, block B
+        let raw = save.raw_data();
+        assert_eq!([13, 0, 0, 0], raw[0x14000..0x14004]);
+        assert_eq!(0xFF, raw[0xE744 + 13 * 9 + 4]);
+        assert_eq!(13, raw[0xE7C2 + 13]);
+        assert_checksums_valid(&save);
+
+        assert!(matches!(
+            save.set_current_box(14),
+            Err(SaveDataError::InvalidBox(14))
+        ));
+
+        // Only the first byte counts, the padding after it is left alone
+        let mut data = save.raw_data().to_vec();
+        data[0x14001] = 0xAB;
+        write_block(&mut data, 0xE000, 11, 1);
+        let mut save = SaveFile::parse(&data).unwrap();
+        assert_eq!(13, save.current_box().unwrap());
+        save.set_current_box(2).unwrap();
+        assert_eq!([2, 0xAB], save.raw_data()[0x14000..0x14002]);
+
+        data[0x14000] = 14;
+        write_block(&mut data, 0xE000, 11, 1);
+        assert!(matches!(
+            SaveFile::parse(&data).unwrap().current_box(),
+            Err(SaveDataError::InvalidBox(14))
+        ));
+        assert!(matches!(
+            save.set_box_name(0, "TOO LONG!"),
+            Err(SaveDataError::InvalidDataLength { .. })
+        ));
+
+        let mut save = SaveFile::parse(&fire_red_save(0x1234_5678)).unwrap();
+        assert!(matches!(
+            save.set_box_wallpaper(0, Wallpaper::Friends),
+            Err(SaveDataError::ValueOutOfRange { value: 16, .. })
+        ));
+    }
+
+    #[test]
+    fn pc_pokemon() {
+        let mut save = SaveFile::parse(&synthetic_save()).unwrap();
+        let torchik = Pokemon::new(0, &TORCHIK);
+
+        assert_eq!(14, save.box_count());
+        assert_eq!(30, save.slots_per_box());
+        assert_eq!(0, save.pc_pokemon().count());
+
+        save.save_pokemon(SlotRef::Box(0, 3), torchik).unwrap();
+        save.save_pokemon(SlotRef::Box(13, 29), torchik).unwrap();
+        let stored: Vec<SlotRef> = save.pc_pokemon().map(|(slot, _)| slot).collect();
+
+        assert_eq!(vec![SlotRef::Box(0, 3), SlotRef::Box(13, 29)], stored);
+        assert!(save
+            .pc_pokemon()
+            .all(|(slot, pokemon)| pokemon.slot() == Some(slot)));
+    }
+
+    #[test]
+    fn pocket_validation() {
+        let mut save = SaveFile::parse(&synthetic_save()).unwrap();
+        let potion = Item::new(13, 5);
+        let mach_bike = Item::new(259, 1);
+        let poke_ball = Item::new(4, 3);
+        let items = [potion, mach_bike, Item::new(13, 100), poke_ball];
+
+        let violations = save.validate_pocket(Pocket::Items, &items).unwrap();
+
+        assert_eq!(
+            vec![
+                PocketViolation::WrongPocket {
+                    slot: 1,
+                    item: mach_bike,
+                    pocket: Pocket::Key
+                },
+                PocketViolation::Duplicate {
+                    slot: 2,
+                    item: Item::new(13, 100),
+                    first_slot: 0
+                },
+                PocketViolation::Quantity {
+                    slot: 2,
+                    item: Item::new(13, 100),
+                    max: 99
+                },
+                PocketViolation::WrongPocket {
+                    slot: 3,
+                    item: poke_ball,
+                    pocket: Pocket::Pokeballs
+                },
+            ],
+            violations
+        );
+        assert!(matches!(
+            save.save_pocket(Pocket::Items, &items),
+            Err(SaveDataError::InvalidPocket { pocket: Pocket::Items, violations }) if violations.len() == 4
+        ));
+        assert_eq!(
+            vec![PocketViolation::Capacity {
+                slots: 16,
+                found: 17
+            }],
+            save.validate_pocket(Pocket::Pokeballs, &[Item::default(); 17])
+                .unwrap()
+        );
+        assert_eq!(
+            vec![PocketViolation::Duplicate {
+                slot: 1,
+                item: potion,
+                first_slot: 0
+            }],
+            save.validate_pocket(Pocket::Items, &[potion, potion])
+                .unwrap()
+        );
+        // Full slots may be followed by more of the same item, anything goes in the PC
+        assert!(save
+            .validate_pocket(
+                Pocket::PcItems,
+                &[Item::new(13, 999), Item::new(13, 1), mach_bike]
+            )
+            .unwrap()
+            .is_empty());
+
+        save.save_pocket_fixed(Pocket::Items, &items).unwrap();
+
+        assert_eq!(
+            vec![Item::new(13, 99), Item::new(13, 6)],
+            save.pocket(Pocket::Items).unwrap()[..2]
+        );
+        assert!(save.pocket(Pocket::Items).unwrap()[2].is_empty());
+        assert_eq!(mach_bike, save.pocket(Pocket::Key).unwrap()[0]);
+        assert_eq!(poke_ball, save.pocket(Pocket::Pokeballs).unwrap()[0]);
+        assert_checksums_valid(&save);
+    }
+
+    #[test]
+    fn sort_pocket() {
+        let mut save = SaveFile::parse(&synthetic_save()).unwrap();
+        let potion = Item::new(13, 5);
+        let antidote = Item::new(14, 1);
+        let super_potion = Item::new(22, 2);
+
+        save.save_pocket(
+            Pocket::Items,
+            &[
+                Item::default(),
+                super_potion,
+                Item::default(),
+                potion,
+                antidote,
+            ],
+        )
+        .unwrap();
+        save.compact_pocket(Pocket::Items).unwrap();
+
+        assert_eq!(
+            vec![super_potion, potion, antidote, Item::default()],
+            save.pocket(Pocket::Items).unwrap()[..4]
+        );
+
+        save.sort_pocket(Pocket::Items, SortOrder::Id).unwrap();
+        assert_eq!(
+            vec![potion, antidote, super_potion],
+            save.pocket(Pocket::Items).unwrap()[..3]
+        );
+
+        save.sort_pocket(Pocket::Items, SortOrder::Name).unwrap();
+        assert_eq!(
+            vec![antidote, potion, super_potion],
+            save.pocket(Pocket::Items).unwrap()[..3]
+        );
+        assert_checksums_valid(&save);
+
+        // HMs come first in the FireRed/LeafGreen TM case
+        let mut save = SaveFile::parse(&fire_red_save(0x1234_5678)).unwrap();
+        let tm01 = Item::new(289, 1);
+        let hm01 = Item::new(339, 1);
+
+        save.save_pocket(Pocket::Tms, &[tm01, Item::default(), hm01])
+            .unwrap();
+        save.sort_pocket(Pocket::Tms, SortOrder::Game).unwrap();
+
+        assert_eq!(
+            vec![hm01, tm01, Item::default()],
+            save.pocket(Pocket::Tms).unwrap()[..3]
+        );
     }
 }